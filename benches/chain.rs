@@ -0,0 +1,32 @@
+//! Compares a five-stage tap and pipe chain against the same work written out
+//! by hand. The two should compile to the same code, and so run in the same
+//! time.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tap::prelude::*;
+
+fn chain(x: u64) -> u64 {
+	x.pipe(|x| x.wrapping_mul(31))
+		.tap(|x| debug_assert!(*x >= 31 || *x == 0))
+		.tap_mut(|x| *x ^= 0x5555)
+		.pipe(|x| x.rotate_left(7))
+		.pipe_ref(|x| x.wrapping_add(1))
+}
+
+fn by_hand(x: u64) -> u64 {
+	let mut x = x.wrapping_mul(31);
+	debug_assert!(x >= 31 || x == 0);
+	x ^= 0x5555;
+	let x = x.rotate_left(7);
+	x.wrapping_add(1)
+}
+
+fn bench(c: &mut Criterion) {
+	let mut group = c.benchmark_group("five_stages");
+	group.bench_function("chain", |b| b.iter(|| chain(black_box(12345))));
+	group.bench_function("by_hand", |b| b.iter(|| by_hand(black_box(12345))));
+	group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -27,7 +27,15 @@ for types with `From`, and manual implementations of `Into`.
 [`into(self) -> T`]: https://doc.rust-lang.org/std/convert/trait.Into.html#tymethod.into
 !*/
 
-use core::convert::TryInto;
+use core::{
+	convert::TryInto, fmt::Debug, iter::FusedIterator, marker::PhantomData,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use core::fmt::{self, Display, Formatter};
 
 /// Wraps `Into::<T>::into` as a method that can be placed in pipelines.
 pub trait Conv
@@ -44,6 +52,9 @@ where
 	/// let len = "Saluton, mondo!"
 	///   .conv::<String>()
 	///   .len();
+	///
+	/// let wide = 5i32.conv::<i64>().pow(20);
+	/// assert_eq!(wide, 95_367_431_640_625);
 	/// ```
 	#[inline(always)]
 	fn conv<T>(self) -> T
@@ -53,6 +64,32 @@ where
 	{
 		Into::<T>::into(self)
 	}
+
+	/// Converts `self` into `T` using `Into<T>`, then pipes the result into a
+	/// function.
+	///
+	/// This is [`Pipe::pipe_into`] with the conversion target named in the
+	/// turbofish. Use it when the function’s argument type cannot be inferred,
+	/// such as a closure that calls methods on its argument.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::conv::Conv;
+	///
+	/// let len = "Saluton".conv_pipe::<String, _>(|s| s.len());
+	/// assert_eq!(len, 7);
+	/// ```
+	///
+	/// [`Pipe::pipe_into`]: ../pipe/trait.Pipe.html#method.pipe_into
+	#[inline(always)]
+	fn conv_pipe<T, R>(self, func: impl FnOnce(T) -> R) -> R
+	where
+		Self: Into<T>,
+		T: Sized,
+	{
+		func(Into::<T>::into(self))
+	}
 }
 
 impl<T> Conv for T {}
@@ -73,6 +110,26 @@ where
 	///   .try_conv::<String>()
 	///   .unwrap()
 	///   .len();
+	///
+	/// assert_eq!(200i64.try_conv::<u8>(), Ok(200));
+	/// assert!(300i64.try_conv::<u8>().is_err());
+	/// ```
+	///
+	/// The target type may also be a reference, for conversions that view a
+	/// borrowed value as some other type only when it fits. The error type is
+	/// whatever the `TryFrom`/`TryInto` implementation for the target declares;
+	/// here, it is `core::array::TryFromSliceError`.
+	///
+	/// ```rust
+	/// use tap::conv::TryConv;
+	///
+	/// let bytes: &[u8] = &[1, 2, 3, 4, 5];
+	///
+	/// let head = bytes[.. 4].try_conv::<&[u8; 4]>().unwrap();
+	/// assert_eq!(head, &[1, 2, 3, 4]);
+	///
+	/// // wrong length: `Err`, not a panic
+	/// assert!(bytes.try_conv::<&[u8; 4]>().is_err());
 	/// ```
 	#[inline(always)]
 	fn try_conv<T>(self) -> Result<T, Self::Error>
@@ -82,6 +139,462 @@ where
 	{
 		TryInto::<T>::try_into(self)
 	}
+
+	/// Converts `self` into `T` using `TryInto<T>`, panicking if the conversion
+	/// fails.
+	///
+	/// The panic is reported at the location of this method call, not inside
+	/// this crate.
+	///
+	/// # Panics
+	///
+	/// Panics with the `Debug` rendering of the conversion error when
+	/// `TryInto::<T>::try_into` fails.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::conv::TryConv;
+	///
+	/// let byte = 200i32.conv_unwrap::<u8>();
+	/// assert_eq!(byte, 200);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// use tap::conv::TryConv;
+	///
+	/// let byte = 300i32.conv_unwrap::<u8>();
+	/// ```
+	#[inline(always)]
+	#[track_caller]
+	fn conv_unwrap<T>(self) -> T
+	where
+		Self: TryInto<T>,
+		Self::Error: Debug,
+		T: Sized,
+	{
+		match TryInto::<T>::try_into(self) {
+			Ok(val) => val,
+			Err(err) => panic!("conversion failed: {:?}", err),
+		}
+	}
+
+	/// Attempts to convert `self` into `T` using `TryInto<T>`, then pipes the
+	/// result into a function if the conversion succeeded.
+	///
+	/// This is [`Pipe::try_pipe_into`] with the conversion target named in the
+	/// turbofish.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::conv::TryConv;
+	///
+	/// let hex = 200i32.try_conv_pipe::<u8, _>(|b| format!("{:02x}", b));
+	/// assert_eq!(hex.unwrap(), "c8");
+	/// assert!(300i32.try_conv_pipe::<u8, _>(|b| b.count_ones()).is_err());
+	/// ```
+	///
+	/// [`Pipe::try_pipe_into`]: ../pipe/trait.Pipe.html#method.try_pipe_into
+	#[inline(always)]
+	fn try_conv_pipe<T, R>(
+		self,
+		func: impl FnOnce(T) -> R,
+	) -> Result<R, Self::Error>
+	where
+		Self: TryInto<T>,
+		T: Sized,
+	{
+		TryInto::<T>::try_into(self).map(func)
+	}
 }
 
 impl<T> TryConv for T {}
+
+/** Converts a value into one chosen default target type.
+
+A type that implements `Into` for several targets must usually name the target
+at each `.conv::<T>()` call. Implementing this trait for it chooses one of those
+targets once, so that `.conv_default()` needs no turbofish. The trait is not
+implemented for any type by default; use the [`conv_default!`] macro, or write
+the one-line implementation by hand.
+
+# Examples
+
+```rust
+use tap::{conv::ConvDefault, conv_default};
+
+struct Meters(f64);
+
+impl From<Meters> for f64 {
+  fn from(m: Meters) -> f64 { m.0 }
+}
+impl From<Meters> for String {
+  fn from(m: Meters) -> String { format!("{} m", m.0) }
+}
+
+conv_default!(Meters => f64);
+
+assert_eq!(Meters(2.5).conv_default(), 2.5);
+```
+
+[`conv_default!`]: ../macro.conv_default.html
+**/
+pub trait ConvDefault
+where
+	Self: Sized + Into<<Self as ConvDefault>::Target>,
+{
+	/// The type that `.conv_default()` converts into.
+	type Target;
+
+	/// Converts `self` into `Self::Target` using `Into<Self::Target>`.
+	#[inline(always)]
+	fn conv_default(self) -> Self::Target {
+		Into::<Self::Target>::into(self)
+	}
+}
+
+/** Chooses the target type of [`ConvDefault::conv_default`] for some types.
+
+Each `Type => Target` pair implements [`ConvDefault`] for `Type` with
+`Target` as its target. `Type` must implement `Into<Target>`, and the impl is
+rejected where the macro is invoked when it does not:
+
+```rust,compile_fail
+use tap::conv_default;
+
+struct Celsius(f64);
+struct Kelvin(f64);
+
+// no `From<Celsius> for Kelvin`
+conv_default!(Celsius => Kelvin);
+```
+
+# Examples
+
+```rust
+use tap::{conv::ConvDefault, conv_default};
+
+struct Id(u32);
+struct Name(&'static str);
+
+impl From<Id> for u64 {
+  fn from(id: Id) -> u64 { id.0 as u64 }
+}
+impl From<Name> for String {
+  fn from(name: Name) -> String { name.0.to_owned() }
+}
+
+conv_default!(Id => u64, Name => String);
+
+assert_eq!(Id(7).conv_default(), 7u64);
+assert_eq!(Name("ada").conv_default(), "ada");
+```
+
+[`ConvDefault`]: conv/trait.ConvDefault.html
+[`ConvDefault::conv_default`]: conv/trait.ConvDefault.html#method.conv_default
+**/
+#[macro_export]
+macro_rules! conv_default {
+	($($ty:ty => $target:ty),+ $(,)?) => {$(
+		impl $crate::conv::ConvDefault for $ty {
+			type Target = $target;
+		}
+	)+};
+}
+
+/// Wraps `Into::<T>::into` and `TryInto::<T>::try_into` as iterator adapters.
+///
+/// These adapters convert each item an iterator yields, in the same manner as
+/// [`Conv`] and [`TryConv`]. The destination type is named on the method, so
+/// the pipeline does not need to be bound to an annotated `let`.
+///
+/// [`Conv`]: trait.Conv.html
+/// [`TryConv`]: trait.TryConv.html
+pub trait ConvIterator
+where
+	Self: Iterator + Sized,
+{
+	/// Converts each item into `T` using `Into<T>`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::conv::ConvIterator;
+	///
+	/// let mean = [1u8, 2, 3, 6]
+	///   .iter()
+	///   .copied()
+	///   .conv_each::<f64>()
+	///   .sum::<f64>() / 4.0;
+	/// assert_eq!(mean, 3.0);
+	/// ```
+	#[inline(always)]
+	fn conv_each<T>(self) -> ConvEach<Self, T>
+	where
+		Self::Item: Into<T>,
+	{
+		ConvEach {
+			iter: self,
+			_dest: PhantomData,
+		}
+	}
+
+	/// Attempts to convert each item into `T` using `TryInto<T>`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::conv::ConvIterator;
+	///
+	/// let bytes = [1i32, 300, 3]
+	///   .iter()
+	///   .copied()
+	///   .try_conv_each::<u8>()
+	///   .collect::<Vec<_>>();
+	/// assert_eq!(bytes[0], Ok(1));
+	/// assert!(bytes[1].is_err());
+	/// assert_eq!(bytes[2], Ok(3));
+	/// ```
+	#[inline(always)]
+	fn try_conv_each<T>(self) -> TryConvEach<Self, T>
+	where
+		Self::Item: TryInto<T>,
+	{
+		TryConvEach {
+			iter: self,
+			_dest: PhantomData,
+		}
+	}
+}
+
+impl<I> ConvIterator for I where I: Iterator {}
+
+/// An iterator that converts each item with `Into`.
+///
+/// This is created by [`ConvIterator::conv_each`].
+///
+/// [`ConvIterator::conv_each`]: trait.ConvIterator.html#method.conv_each
+#[must_use = "iterator adapters are lazy, and do nothing unless consumed"]
+pub struct ConvEach<I, T> {
+	iter: I,
+	_dest: PhantomData<fn() -> T>,
+}
+
+impl<I, T> Clone for ConvEach<I, T>
+where
+	I: Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		ConvEach {
+			iter: self.iter.clone(),
+			_dest: PhantomData,
+		}
+	}
+}
+
+impl<I, T> Iterator for ConvEach<I, T>
+where
+	I: Iterator,
+	I::Item: Into<T>,
+{
+	type Item = T;
+
+	#[inline]
+	fn next(&mut self) -> Option<T> {
+		self.iter.next().map(Into::into)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
+
+impl<I, T> DoubleEndedIterator for ConvEach<I, T>
+where
+	I: DoubleEndedIterator,
+	I::Item: Into<T>,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<T> {
+		self.iter.next_back().map(Into::into)
+	}
+}
+
+impl<I, T> ExactSizeIterator for ConvEach<I, T>
+where
+	I: ExactSizeIterator,
+	I::Item: Into<T>,
+{
+}
+
+impl<I, T> FusedIterator for ConvEach<I, T>
+where
+	I: FusedIterator,
+	I::Item: Into<T>,
+{
+}
+
+/// An iterator that attempts to convert each item with `TryInto`.
+///
+/// This is created by [`ConvIterator::try_conv_each`].
+///
+/// [`ConvIterator::try_conv_each`]: trait.ConvIterator.html#method.try_conv_each
+#[must_use = "iterator adapters are lazy, and do nothing unless consumed"]
+pub struct TryConvEach<I, T> {
+	iter: I,
+	_dest: PhantomData<fn() -> T>,
+}
+
+impl<I, T> Clone for TryConvEach<I, T>
+where
+	I: Clone,
+{
+	#[inline]
+	fn clone(&self) -> Self {
+		TryConvEach {
+			iter: self.iter.clone(),
+			_dest: PhantomData,
+		}
+	}
+}
+
+impl<I, T> Iterator for TryConvEach<I, T>
+where
+	I: Iterator,
+	I::Item: TryInto<T>,
+{
+	type Item = Result<T, <I::Item as TryInto<T>>::Error>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.iter.next().map(TryInto::try_into)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
+
+impl<I, T> DoubleEndedIterator for TryConvEach<I, T>
+where
+	I: DoubleEndedIterator,
+	I::Item: TryInto<T>,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.iter.next_back().map(TryInto::try_into)
+	}
+}
+
+impl<I, T> ExactSizeIterator for TryConvEach<I, T>
+where
+	I: ExactSizeIterator,
+	I::Item: TryInto<T>,
+{
+}
+
+impl<I, T> FusedIterator for TryConvEach<I, T>
+where
+	I: FusedIterator,
+	I::Item: TryInto<T>,
+{
+}
+
+/// Converts a collection into a fixed-size array, directed by the array length.
+///
+/// This trait is only available when the `alloc` feature is enabled.
+///
+/// `Vec<T>` already implements `TryInto<[T; N]>`, so `.try_conv::<[T; N]>()`
+/// works, but its error is the original vector with no explanation. This trait
+/// places the length on the method, and reports both the expected and the
+/// actual lengths when they differ.
+#[cfg(feature = "alloc")]
+pub trait TryConvArray
+where
+	Self: Sized,
+{
+	/// The element type of the array produced by the conversion.
+	type Item;
+
+	/// Attempts to convert `self` into an array of exactly `N` elements.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::conv::TryConvArray;
+	///
+	/// let arr = vec![1u8, 2, 3].try_conv_array::<3>().unwrap();
+	/// assert_eq!(arr, [1, 2, 3]);
+	///
+	/// let err = vec![1u8, 2, 3].try_conv_array::<4>().unwrap_err();
+	/// assert_eq!(err.to_string(), "expected 4 elements, got 3");
+	/// assert_eq!(err.into_inner(), [1, 2, 3]);
+	/// ```
+	fn try_conv_array<const N: usize>(
+		self,
+	) -> Result<[Self::Item; N], LengthMismatch<Self>>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryConvArray for Vec<T> {
+	type Item = T;
+
+	#[inline]
+	fn try_conv_array<const N: usize>(
+		self,
+	) -> Result<[T; N], LengthMismatch<Self>> {
+		TryInto::<[T; N]>::try_into(self).map_err(|value| LengthMismatch {
+			expected: N,
+			got: value.len(),
+			value,
+		})
+	}
+}
+
+/// The error produced when a collection has the wrong length to become an
+/// array.
+///
+/// It carries the original collection, so that it is not lost when the
+/// conversion fails.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LengthMismatch<T> {
+	value: T,
+	expected: usize,
+	got: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> LengthMismatch<T> {
+	/// The length the conversion required.
+	#[inline]
+	pub fn expected(&self) -> usize {
+		self.expected
+	}
+
+	/// The length the collection actually had.
+	#[inline]
+	pub fn got(&self) -> usize {
+		self.got
+	}
+
+	/// Recovers the collection that failed to convert.
+	#[inline]
+	pub fn into_inner(self) -> T {
+		self.value
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Display for LengthMismatch<T> {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "expected {} elements, got {}", self.expected, self.got)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<T> core::error::Error for LengthMismatch<T> where T: Debug {}
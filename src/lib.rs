@@ -34,8 +34,10 @@ code. The effect function receives a borrow of the tapped value, optionally run
 through the `Borrow`, `AsRef`, or `Deref` view conversions, for the duration of
 its execution.
 
-The effect function cannot return a value, as the tap is incapable of handling
-it.
+For `tap` and `tap_mut`, and for the taps in `TapOptional`, `TapFallible`, and
+`TapControlFlow`, the effect function may return any value; the tap has no use
+for it, so it is discarded. The view taps, such as `tap_borrow`, `tap_ref`, and
+`tap_deref`, still require effect functions that return `()`.
 
 ## Piping
 
@@ -117,26 +119,68 @@ pub trait Conv: Sized {
 Each monomorphization of `.conv::<T>()` expands to the appropriate `Into<T>`
 implementation, and does nothing else.
 
+## Cargo Features
+
+All features are disabled by default. The default build needs Rust 1.63; some
+features need a newer compiler, as noted below.
+
+- `alloc`: provides the [`TryConvArray`] trait, which converts a `Vec<T>` into
+  a `[T; N]` with a descriptive length-mismatch error, and the [`Tapped`]
+  type, which stores taps on a value and runs them later. Needs Rust 1.81.
+- `async`: provides the [`AsyncTap`] trait, whose taps await an asynchronous
+  effect function, which may borrow the value, before returning it. Needs Rust
+  1.85.
+- `serde`: provides [`Tap::tap_json_dbg`], which prints the tapped value as
+  pretty-printed JSON in debug builds. This enables `std`, and so needs Rust
+  1.81.
+- `std`: links the standard library, for the methods that need to print or to
+  catch panics, such as [`Pipe::pipe_dbg`] and [`Pipe::pipe_catch_unwind`].
+  This enables `alloc`, and so needs Rust 1.81.
+- `tracing`: provides the [`TapTrace`] and [`TapErrTrace`] traits, whose taps
+  emit [`tracing`] events carrying the `Debug` rendering of the tapped value.
+  The events’ target is `tap::tap`, so filters must enable it, for example
+  with `tap=trace`.
+  Needs the `tracing` crate’s minimum Rust version, currently 1.65.
+
+[`AsyncTap`]: tap/trait.AsyncTap.html
 [`Conv`]: conv/trait.Conv.html
 [`Pipe`]: pipe/trait.Pipe.html
+[`Pipe::pipe_catch_unwind`]: pipe/trait.Pipe.html#method.pipe_catch_unwind
+[`Pipe::pipe_dbg`]: pipe/trait.Pipe.html#method.pipe_dbg
 [`Tap`]: tap/trait.Tap.html
+[`Tap::tap_json_dbg`]: tap/trait.Tap.html#method.tap_json_dbg
+[`TapErrTrace`]: tap/trait.TapErrTrace.html
 [`TapFallible`]: tap/trait.TapFallible.html
 [`TapOptional`]: tap/trait.TapOptional.html
+[`TapTrace`]: tap/trait.TapTrace.html
+[`Tapped`]: tap/struct.Tapped.html
 [`TryConv`]: conv/trait.TryConv.html
+[`TryConvArray`]: conv/trait.TryConvArray.html
 [`conv`]: conv/index.html
 [`pipe`]: pipe/index.html
 [`tap`]: tap/index.html
+[`tracing`]: https://docs.rs/tracing
 !*/
 
 #![no_std]
 #![cfg_attr(debug_assertions, warn(missing_docs))]
 #![cfg_attr(not(debug_assertions), deny(missing_docs))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod conv;
 pub mod pipe;
 pub mod tap;
 
 /// Reëxports all traits in one place, for easy import.
+///
+/// Every method name is unique across the crate’s traits, so
+/// `use tap::prelude::*;` never makes a method call ambiguous. New methods must
+/// keep it that way.
 pub mod prelude {
 	#[doc(inline)]
 	pub use crate::{conv::*, pipe::*, tap::*};
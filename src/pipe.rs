@@ -11,14 +11,25 @@ you do have a use for them.
 This module is as much of a [UFCS] method syntax that can be provided as a
 library, rather than in the language grammar.
 
+The `Callable` trait provides the same call from the other side:
+`func.call_with(value)` is `value.pipe(func)`, for code that holds a function
+and applies an argument to it.
+
 [UFCS]: https://en.wikipedia.org/wiki/Uniform_Function_Call_Syntax
 !*/
 
 use core::{
 	borrow::{Borrow, BorrowMut},
+	convert::TryInto,
 	ops::{Deref, DerefMut},
 };
 
+#[cfg(feature = "std")]
+use core::{any::Any, fmt::Debug, panic::Location};
+
+#[cfg(feature = "std")]
+use std::{boxed::Box, panic::UnwindSafe};
+
 /** Provides universal suffix-position call syntax for any function.
 
 This trait provides methods that allow any closure or free function to be placed
@@ -78,6 +89,190 @@ pub trait Pipe {
 		func(self)
 	}
 
+	/// Converts `self` with `Into<T>`, then pipes the result by value.
+	///
+	/// The conversion target does not need to be named: it is inferred from the
+	/// argument type of the pipe function.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::Pipe;
+	///
+	/// fn shout(text: String) -> String {
+	///   text.to_uppercase()
+	/// }
+	///
+	/// assert_eq!("hello".pipe_into(shout), "HELLO");
+	/// ```
+	#[inline(always)]
+	fn pipe_into<T, R>(self, func: impl FnOnce(T) -> R) -> R
+	where
+		Self: Sized + Into<T>,
+		R: Sized,
+	{
+		func(Into::<T>::into(self))
+	}
+
+	/// Attempts to convert `self` with `TryInto<T>`, then pipes the result by
+	/// value if the conversion succeeded.
+	///
+	/// The conversion error is returned without calling the pipe function.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::Pipe;
+	///
+	/// fn halve(x: u8) -> u8 {
+	///   x / 2
+	/// }
+	///
+	/// assert_eq!(200i32.try_pipe_into(halve), Ok(100));
+	/// assert!(300i32.try_pipe_into(halve).is_err());
+	/// ```
+	#[inline(always)]
+	fn try_pipe_into<T, R>(
+		self,
+		func: impl FnOnce(T) -> R,
+	) -> Result<R, Self::Error>
+	where
+		Self: Sized + TryInto<T>,
+		R: Sized,
+	{
+		TryInto::<T>::try_into(self).map(func)
+	}
+
+	/// Repeatedly pipes a value through `step`, until `pred` holds for it.
+	///
+	/// `step` is applied only while `pred` returns false, so a value that
+	/// already satisfies `pred` is returned without calling `step` at all. The
+	/// value is moved through each step, and never cloned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::Pipe;
+	///
+	/// let small = 1000.pipe_while(|n| *n < 10, |n| n / 2);
+	/// assert_eq!(small, 7);
+	/// ```
+	#[inline(always)]
+	fn pipe_while(
+		self,
+		pred: impl Fn(&Self) -> bool,
+		mut step: impl FnMut(Self) -> Self,
+	) -> Self
+	where
+		Self: Sized,
+	{
+		let mut val = self;
+		while !pred(&val) {
+			val = step(val);
+		}
+		val
+	}
+
+	/// Pipes `self` by value into `func`, then inspects the result with `tap`
+	/// before returning it.
+	///
+	/// This is `self.pipe(func).tap(tap)` in a single call.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::Pipe;
+	/// # macro_rules! log { ($msg:literal, $x:ident) => {{}}; }
+	///
+	/// let port = "8080"
+	///   .pipe_tap(str::parse::<u16>, |p| log!("parsed {:?}", p))
+	///   .unwrap();
+	/// assert_eq!(port, 8080);
+	/// ```
+	#[inline(always)]
+	fn pipe_tap<R>(self, func: impl FnOnce(Self) -> R, tap: impl FnOnce(&R)) -> R
+	where
+		Self: Sized,
+		R: Sized,
+	{
+		let out = func(self);
+		tap(&out);
+		out
+	}
+
+	/// Prints the value in the manner of `std::dbg!` only in debug builds, and
+	/// passes it through unchanged.
+	///
+	/// This method is only available when the `std` feature is enabled. The
+	/// value is pretty-printed with its `Debug` implementation to standard
+	/// error, prefixed with the location of the method call. Unlike `dbg!`, a
+	/// method cannot see the source text of its receiver, so the expression is
+	/// not printed. The value is moved through, and never cloned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::Pipe;
+	///
+	/// let n = "42".parse::<i32>().unwrap().pipe_dbg().pipe(|n| n * 2);
+	/// assert_eq!(n, 84);
+	/// ```
+	#[cfg(feature = "std")]
+	#[inline(always)]
+	#[track_caller]
+	fn pipe_dbg(self) -> Self
+	where
+		Self: Sized + Debug,
+	{
+		if cfg!(debug_assertions) {
+			let loc = Location::caller();
+			std::eprintln!(
+				"[{}:{}:{}] {:#?}",
+				loc.file(),
+				loc.line(),
+				loc.column(),
+				self,
+			);
+		}
+		self
+	}
+
+	/// Pipes `self` by value into a function, catching any panic it raises.
+	///
+	/// This method is only available when the `std` feature is enabled. It
+	/// wraps `std::panic::catch_unwind`, and returns `Ok` with the function’s
+	/// output, or `Err` with the panic payload. Both `self` and the function
+	/// must be `UnwindSafe`; wrap them in `std::panic::AssertUnwindSafe` if you
+	/// know this to be sound.
+	///
+	/// The panic hook still runs before the panic is caught, so the usual
+	/// message is printed unless the hook has been replaced. Panics are only
+	/// caught when the crate is built with `panic = "unwind"`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::Pipe;
+	///
+	/// let ok = 4.pipe_catch_unwind(|x| x * 2);
+	/// assert_eq!(ok.unwrap(), 8);
+	///
+	/// # std::panic::set_hook(Box::new(|_| {}));
+	/// let err = 0.pipe_catch_unwind(|x: i32| 1 / x);
+	/// assert!(err.is_err());
+	/// ```
+	#[cfg(feature = "std")]
+	#[inline(always)]
+	fn pipe_catch_unwind<R>(
+		self,
+		func: impl FnOnce(Self) -> R + UnwindSafe,
+	) -> Result<R, Box<dyn Any + Send>>
+	where
+		Self: Sized + UnwindSafe,
+	{
+		std::panic::catch_unwind(move || func(self))
+	}
+
 	/// Borrows `self` and passes that borrow into the pipe function.
 	///
 	/// # Examples
@@ -131,6 +326,13 @@ pub trait Pipe {
 
 	/// Borrows `self`, then passes `self.borrow()` into the pipe function.
 	///
+	/// Prefer this over [`Pipe::pipe_as_ref`] when the borrowed view must
+	/// behave identically to the owned value. `Borrow` requires that `Eq`,
+	/// `Ord` and `Hash` agree between the two, which is why map and set
+	/// lookups use it: a `String` key can be found with a `&str`. `AsRef` makes
+	/// no such promise, and is a looser, cheap reference conversion such as
+	/// `String` to `Path`.
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -141,6 +343,22 @@ pub trait Pipe {
 	///   .pipe_borrow(str::len);
 	/// assert_eq!(len, 12);
 	/// ```
+	///
+	/// An owned key can be used to look up a map through its borrowed form:
+	///
+	/// ```rust
+	/// use std::collections::HashMap;
+	/// use tap::pipe::Pipe;
+	///
+	/// let ports: HashMap<String, u16> =
+	///   HashMap::from([("http".into(), 80), ("https".into(), 443)]);
+	///
+	/// let scheme = String::from("https");
+	/// let port = scheme.pipe_borrow(|key: &str| ports.get(key).copied());
+	/// assert_eq!(port, Some(443));
+	/// ```
+	///
+	/// [`Pipe::pipe_as_ref`]: trait.Pipe.html#method.pipe_as_ref
 	#[inline(always)]
 	fn pipe_borrow<'a, B, R>(&'a self, func: impl FnOnce(&'a B) -> R) -> R
 	where
@@ -154,6 +372,17 @@ pub trait Pipe {
 	/// Mutably borrows `self`, then passes `self.borrow_mut()` into the pipe
 	/// function.
 	///
+	/// As with [`Pipe::pipe_borrow`], the `BorrowMut` view is guaranteed to
+	/// compare and hash the same as the owned value.
+	///
+	/// ```rust
+	/// use tap::pipe::Pipe;
+	///
+	/// let mut keys = vec![3, 1, 2];
+	/// keys.pipe_borrow_mut(<[i32]>::sort);
+	/// assert_eq!(keys, [1, 2, 3]);
+	/// ```
+	///
 	/// ```rust
 	/// use tap::pipe::Pipe;
 	///
@@ -165,6 +394,8 @@ pub trait Pipe {
 	/// This is a very contrived example, but the `BorrowMut` trait has almost
 	/// no implementors in the standard library, and of the implementations
 	/// available, there are almost no methods that fit this API.
+	///
+	/// [`Pipe::pipe_borrow`]: trait.Pipe.html#method.pipe_borrow
 	#[inline(always)]
 	fn pipe_borrow_mut<'a, B, R>(
 		&'a mut self,
@@ -179,6 +410,23 @@ pub trait Pipe {
 	}
 
 	/// Borrows `self`, then passes `self.as_ref()` into the pipe function.
+	///
+	/// The `AsRef` target is usually inferred from the argument type of the
+	/// pipe function, so annotating the closure parameter is enough to select
+	/// which view the function receives.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use std::path::Path;
+	/// use tap::pipe::Pipe;
+	///
+	/// let name = "Cargo.toml".to_string();
+	/// let is_toml = name.pipe_as_ref(|p: &Path| {
+	///   p.extension().map_or(false, |ext| ext == "toml")
+	/// });
+	/// assert!(is_toml);
+	/// ```
 	#[inline(always)]
 	fn pipe_as_ref<'a, U, R>(&'a self, func: impl FnOnce(&'a U) -> R) -> R
 	where
@@ -191,6 +439,25 @@ pub trait Pipe {
 
 	/// Mutably borrows `self`, then passes `self.as_mut()` into the pipe
 	/// function.
+	///
+	/// As with [`Pipe::pipe_as_ref`], the `AsMut` target is usually inferred
+	/// from the argument type of the pipe function.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::Pipe;
+	///
+	/// let mut bytes = b"hello".to_vec();
+	/// let first = bytes.pipe_as_mut(|b: &mut [u8]| {
+	///   b.make_ascii_uppercase();
+	///   b[0]
+	/// });
+	/// assert_eq!(first, b'H');
+	/// assert_eq!(bytes, b"HELLO");
+	/// ```
+	///
+	/// [`Pipe::pipe_as_ref`]: trait.Pipe.html#method.pipe_as_ref
 	#[inline(always)]
 	fn pipe_as_mut<'a, U, R>(
 		&'a mut self,
@@ -205,6 +472,17 @@ pub trait Pipe {
 	}
 
 	/// Borrows `self`, then passes `self.deref()` into the pipe function.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use std::rc::Rc;
+	/// use tap::pipe::Pipe;
+	///
+	/// let shared = Rc::new([3, 1, 2]);
+	/// let max = shared.pipe_deref(|arr: &[i32; 3]| arr.iter().max().copied());
+	/// assert_eq!(max, Some(3));
+	/// ```
 	#[inline(always)]
 	fn pipe_deref<'a, T, R>(&'a self, func: impl FnOnce(&'a T) -> R) -> R
 	where
@@ -217,6 +495,17 @@ pub trait Pipe {
 
 	/// Mutably borrows `self`, then passes `self.deref_mut()` into the pipe
 	/// function.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::Pipe;
+	///
+	/// let mut boxed = Box::new(vec![1, 2, 3]);
+	/// let last = boxed.pipe_deref_mut(Vec::pop);
+	/// assert_eq!(last, Some(3));
+	/// assert_eq!(*boxed, [1, 2]);
+	/// ```
 	#[inline(always)]
 	fn pipe_deref_mut<'a, T, R>(
 		&'a mut self,
@@ -232,3 +521,209 @@ pub trait Pipe {
 }
 
 impl<T> Pipe for T where T: ?Sized {}
+
+/** Optional piping, conditional on the optional presence of a value.
+
+This trait is the piping counterpart to [`TapOptional`]: it forwards the
+interior value of a container into a pipe function only when that value is
+present, and passes an empty container through without calling the function.
+
+For `Option`, `.pipe_some()` is equivalent to `Option::map`, `.try_pipe_some()`
+is equivalent to `Option::and_then`, and `.try_pipe_some_ok()` is equivalent to
+`.map(func).transpose()`. They are provided so
+that pipelines over optional values can use the same vocabulary as the rest of
+this crate.
+
+[`TapOptional`]: ../tap/trait.TapOptional.html
+**/
+pub trait PipeOptional
+where
+	Self: Sized,
+{
+	/// The interior type that the container may or may not carry.
+	type Val;
+
+	/// Pipes the interior value by value, when it is present.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::PipeOptional;
+	///
+	/// fn triple(x: i32) -> i64 {
+	///   x as i64 * 3
+	/// }
+	///
+	/// assert_eq!(Some(10).pipe_some(triple), Some(30));
+	/// assert_eq!(None.pipe_some(triple), None);
+	/// // the same as
+	/// assert_eq!(Some(10).map(triple), Some(30));
+	/// ```
+	fn pipe_some<R>(self, func: impl FnOnce(Self::Val) -> R) -> Option<R>;
+
+	/// Pipes the interior value by value, when it is present, into a function
+	/// that may itself produce nothing.
+	///
+	/// The result is flattened, so that an empty input and an empty output
+	/// both produce `None`. For a function that returns `Result`, use
+	/// [`PipeOptional::try_pipe_some_ok`], which keeps the error.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::PipeOptional;
+	///
+	/// fn parse(text: &str) -> Option<i32> {
+	///   text.parse().ok()
+	/// }
+	///
+	/// assert_eq!(Some("5").try_pipe_some(parse), Some(5));
+	/// assert_eq!(Some("five").try_pipe_some(parse), None);
+	/// assert_eq!(None.try_pipe_some(parse), None);
+	/// // the same as
+	/// assert_eq!(Some("5").and_then(parse), Some(5));
+	/// ```
+	///
+	/// [`PipeOptional::try_pipe_some_ok`]: trait.PipeOptional.html#tymethod.try_pipe_some_ok
+	fn try_pipe_some<R>(
+		self,
+		func: impl FnOnce(Self::Val) -> Option<R>,
+	) -> Option<R>;
+
+	/// Pipes the interior value by value, when it is present, into a function
+	/// that may fail.
+	///
+	/// The result is flattened into `Result<Option<R>, E>`: an empty input
+	/// produces `Ok(None)` without calling the function, and an error from the
+	/// function is passed through as `Err`, ready for `?`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use std::num::ParseIntError;
+	/// use tap::pipe::PipeOptional;
+	///
+	/// fn parse(text: &str) -> Result<i32, ParseIntError> {
+	///   text.parse()
+	/// }
+	///
+	/// assert_eq!(Some("5").try_pipe_some_ok(parse), Ok(Some(5)));
+	/// assert!(Some("five").try_pipe_some_ok(parse).is_err());
+	/// assert_eq!(None.try_pipe_some_ok(parse), Ok(None));
+	/// // the same as
+	/// assert_eq!(Some("5").map(parse).transpose(), Ok(Some(5)));
+	/// ```
+	fn try_pipe_some_ok<R, E>(
+		self,
+		func: impl FnOnce(Self::Val) -> Result<R, E>,
+	) -> Result<Option<R>, E>;
+}
+
+impl<T> PipeOptional for Option<T> {
+	type Val = T;
+
+	#[inline(always)]
+	fn pipe_some<R>(self, func: impl FnOnce(T) -> R) -> Option<R> {
+		self.map(func)
+	}
+
+	#[inline(always)]
+	fn try_pipe_some<R>(self, func: impl FnOnce(T) -> Option<R>) -> Option<R> {
+		self.and_then(func)
+	}
+
+	#[inline(always)]
+	fn try_pipe_some_ok<R, E>(
+		self,
+		func: impl FnOnce(T) -> Result<R, E>,
+	) -> Result<Option<R>, E> {
+		self.map(func).transpose()
+	}
+}
+
+/** Calls a function with an argument, from the function’s side.
+
+This is the inverse of [`Pipe::pipe`]: `value.pipe(func)` and
+`func.call_with(value)` both evaluate `func(value)`. Reach for `.pipe()` when
+the value is what the chain is about and the function is one step in it, and
+for `.call_with()` when the function is the stored or computed thing, such as a
+closure chosen by a builder, and the argument is applied to it.
+
+[`Pipe::pipe`]: trait.Pipe.html#method.pipe
+**/
+pub trait Callable {
+	/// Calls `self` with `arg`, returning the result.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::pipe::Callable;
+	///
+	/// let scale = 3;
+	/// let op = move |x: i32| x * scale;
+	/// assert_eq!(op.call_with(5), 15);
+	///
+	/// let parse: fn(&str) -> Option<u8> = |s| s.parse().ok();
+	/// assert_eq!(parse.call_with("42"), Some(42));
+	/// ```
+	#[inline(always)]
+	fn call_with<A, R>(self, arg: A) -> R
+	where
+		Self: Sized + FnOnce(A) -> R,
+	{
+		self(arg)
+	}
+}
+
+impl<T> Callable for T where T: ?Sized {}
+
+/** Writes a sequence of pipes as a left-to-right chain.
+
+The first operand is the starting value, and each operand after a `=>` is a
+stage that receives the output of the stage before it. A stage may be
+
+- a path to a function, such as `str::len` or `u8::from`,
+- a closure, such as `|x| x + 1`,
+- or a method call, written with a leading dot, such as `.to_string()`.
+
+Function and closure stages forward to [`Pipe::pipe`], so they take the value
+by move, exactly as a written-out `.pipe()` chain would. `pipe!(value => f => g)`
+therefore evaluates to `g(f(value))`.
+
+# Examples
+
+```rust
+use tap::pipe;
+
+fn double(x: i32) -> i32 { x * 2 }
+
+let out = pipe!(5 => double => |x| x + 1 => .to_string() => String::into_bytes);
+assert_eq!(out, b"11");
+```
+
+[`Pipe::pipe`]: pipe/trait.Pipe.html#method.pipe
+**/
+#[macro_export]
+macro_rules! pipe {
+	(@step $acc:expr) => {
+		$acc
+	};
+
+	(@step $acc:expr => . $method:ident $(:: < $($gen:ty),+ >)? (
+		$($args:tt)*
+	) $($rest:tt)*) => {
+		$crate::pipe!(@step $acc.$method $(::<$($gen),+>)? ($($args)*) $($rest)*)
+	};
+
+	(@step $acc:expr => $func:expr => $($rest:tt)+) => {
+		$crate::pipe!(@step $crate::pipe::Pipe::pipe($acc, $func) => $($rest)+)
+	};
+
+	(@step $acc:expr => $func:expr) => {
+		$crate::pipe::Pipe::pipe($acc, $func)
+	};
+
+	($value:expr $(=> $($rest:tt)+)?) => {
+		$crate::pipe!(@step $value $(=> $($rest)+)?)
+	};
+}
@@ -34,9 +34,25 @@ make_vec().tap_deref_mut(<[_]>::sort);
 
 use core::{
 	borrow::{Borrow, BorrowMut},
-	ops::{Deref, DerefMut},
+	iter::FusedIterator,
+	ops::{ControlFlow, Deref, DerefMut},
 };
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+#[cfg(feature = "async")]
+use core::future::Future;
+
+#[cfg(feature = "tracing")]
+use core::fmt::Debug;
+
+#[cfg(any(feature = "serde", feature = "tracing"))]
+use core::panic::Location;
+
+#[cfg(feature = "tracing")]
+use tracing::Level;
+
 /** Point-free value inspection and modification.
 
 This trait provides methods that permit viewing the value of an expression
@@ -45,6 +61,28 @@ code other than insertion of the `.tap()` call.
 
 The methods in this trait do not perform any view conversions on the value they
 receive; it is borrowed and passed directly to the effect argument.
+
+Unsized values such as `str` and `[T]` cannot be tapped directly, but references
+to them are `Sized`, so the reference itself can be tapped. The effect function
+receives `&&str`, or `&&mut [T]` / `&mut &mut [T]` for `tap` / `tap_mut`, which
+auto-deref at method calls, and the chain keeps the original reference with its
+original lifetime.
+
+```rust
+use tap::tap::Tap;
+
+fn first_word(text: &str) -> &str {
+  let word = text.split(' ').next().unwrap_or("");
+  word.tap(|w| assert!(w.len() <= text.len()))
+}
+assert_eq!(first_word("hello world"), "hello");
+
+let mut buf = [3u8, 1, 2];
+let bytes: &mut [u8] = (&mut buf[..])
+  .tap_mut(|s| s.sort())
+  .tap(|s| assert_eq!(s.len(), 3));
+assert_eq!(bytes, &[1, 2, 3]);
+```
 **/
 pub trait Tap
 where
@@ -74,8 +112,22 @@ where
 	///   .tap(|v| log!("The produced value was: {}", v))
 	///   .process_value();
 	/// ```
+	///
+	/// The effect function may return a value, which is discarded. This allows
+	/// fallible effects, such as writing to a formatter, to be used without a
+	/// trailing `;` or `let _ =`.
+	///
+	/// ```rust
+	/// use std::fmt::Write;
+	/// use tap::tap::Tap;
+	///
+	/// let mut log = String::new();
+	/// let val = 5.tap(|v| writeln!(log, "value: {}", v));
+	/// assert_eq!(val, 5);
+	/// assert_eq!(log, "value: 5\n");
+	/// ```
 	#[inline(always)]
-	fn tap(self, func: impl FnOnce(&Self)) -> Self {
+	fn tap<R>(self, func: impl FnOnce(&Self) -> R) -> Self {
 		func(&self);
 		self
 	}
@@ -112,8 +164,15 @@ where
 	/// The mutable tap is a convenient alternative when the expression to
 	/// produce the collection is more complex, for example, an iterator
 	/// pipeline collected into a vector.
+	///
+	/// ```rust
+	/// use tap::tap::Tap;
+	///
+	/// let sorted = vec![3, 1, 2].tap_mut(|v| v.sort());
+	/// assert_eq!(sorted, [1, 2, 3]);
+	/// ```
 	#[inline(always)]
-	fn tap_mut(mut self, func: impl FnOnce(&mut Self)) -> Self {
+	fn tap_mut<R>(mut self, func: impl FnOnce(&mut Self) -> R) -> Self {
 		func(&mut self);
 		self
 	}
@@ -220,21 +279,221 @@ where
 		self
 	}
 
+	//  runtime-conditional copies of `.tap()` and `.tap_mut()`
+
+	/// Calls `.tap()` only when `cond` is true.
+	///
+	/// The effect function is not evaluated at all when `cond` is false.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::Tap;
+	/// # macro_rules! log { ($msg:literal, $x:ident) => {{}}; }
+	///
+	/// let verbose = false;
+	/// let val = 5.tap_if(verbose, |v| log!("Value: {}", v));
+	/// assert_eq!(val, 5);
+	/// ```
+	#[inline(always)]
+	fn tap_if<R>(self, cond: bool, func: impl FnOnce(&Self) -> R) -> Self {
+		if cond {
+			func(&self);
+		}
+		self
+	}
+
+	/// Calls `.tap_mut()` only when `cond` is true.
+	///
+	/// The effect function is not evaluated at all when `cond` is false.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::Tap;
+	///
+	/// let verbose = true;
+	/// let msg = "Saluton, mondo!"
+	///   .to_string()
+	///   .tap_mut_if(verbose, |x| x.push_str(" [debug]"));
+	/// assert_eq!(msg, "Saluton, mondo! [debug]");
+	/// ```
+	#[inline(always)]
+	fn tap_mut_if<R>(
+		mut self,
+		cond: bool,
+		func: impl FnOnce(&mut Self) -> R,
+	) -> Self {
+		if cond {
+			func(&mut self);
+		}
+		self
+	}
+
+	/// Calls `.tap()` only when `cond` is false.
+	///
+	/// This is the negation of [`Tap::tap_if`].
+	///
+	/// [`Tap::tap_if`]: trait.Tap.html#method.tap_if
+	#[inline(always)]
+	fn tap_unless<R>(self, cond: bool, func: impl FnOnce(&Self) -> R) -> Self {
+		self.tap_if(!cond, func)
+	}
+
+	/// Calls `.tap_mut()` only when `cond` is false.
+	///
+	/// This is the negation of [`Tap::tap_mut_if`].
+	///
+	/// [`Tap::tap_mut_if`]: trait.Tap.html#method.tap_mut_if
+	#[inline(always)]
+	fn tap_mut_unless<R>(
+		self,
+		cond: bool,
+		func: impl FnOnce(&mut Self) -> R,
+	) -> Self {
+		self.tap_mut_if(!cond, func)
+	}
+
+	/// Asserts that `pred` holds for a value, then returns it.
+	///
+	/// When the predicate fails, this panics with `msg`. The panic is reported
+	/// at the location of this method call, not inside this crate. When the
+	/// predicate holds, the value is returned without being moved or cloned
+	/// anywhere else.
+	///
+	/// # Panics
+	///
+	/// Panics with `msg` if `pred` returns false.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::Tap;
+	///
+	/// let evens = vec![2, 4, 6]
+	///   .tap_assert(|v| v.iter().all(|x| x % 2 == 0), "all items are even")
+	///   .len();
+	/// assert_eq!(evens, 3);
+	/// ```
+	///
+	/// ```rust,should_panic
+	/// use tap::tap::Tap;
+	///
+	/// let empty = Vec::<i32>::new().tap_assert(|v| !v.is_empty(), "no items");
+	/// ```
+	#[inline(always)]
+	#[track_caller]
+	fn tap_assert(self, pred: impl FnOnce(&Self) -> bool, msg: &str) -> Self {
+		if !pred(&self) {
+			panic!("{}", msg);
+		}
+		self
+	}
+
+	/// Keeps a value only if `pred` holds for it.
+	///
+	/// This returns `Some(self)` when the predicate returns true, and drops the
+	/// value and returns `None` otherwise. The predicate only borrows the value,
+	/// so no `Clone` bound is needed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::Tap;
+	///
+	/// struct Config {
+	///   threads: usize,
+	/// }
+	///
+	/// fn build(threads: usize) -> Option<Config> {
+	///   Config { threads }.tap_filter(|c| c.threads > 0)
+	/// }
+	///
+	/// assert_eq!(build(4).map(|c| c.threads), Some(4));
+	/// assert!(build(0).is_none());
+	/// ```
+	#[inline(always)]
+	fn tap_filter(self, pred: impl FnOnce(&Self) -> bool) -> Option<Self> {
+		if pred(&self) {
+			Some(self)
+		} else {
+			None
+		}
+	}
+
+	/// Mutably accesses a value with a fallible effect function.
+	///
+	/// On success, the mutated value is returned in `Ok`. On failure, the value
+	/// is dropped and the error is returned. This lifts an infallible chain of
+	/// taps into a `Result` at the first step that can fail.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::Tap;
+	///
+	/// #[derive(Default)]
+	/// struct Server {
+	///   port: u16,
+	///   workers: usize,
+	/// }
+	///
+	/// fn build(port: &str) -> Result<Server, std::num::ParseIntError> {
+	///   Server::default()
+	///     .tap_mut(|s| s.workers = 4)
+	///     .tap_try_mut(|s| {
+	///       s.port = port.parse()?;
+	///       Ok(())
+	///     })
+	/// }
+	///
+	/// assert_eq!(build("8080").map(|s| (s.port, s.workers)), Ok((8080, 4)));
+	/// assert!(build("http").is_err());
+	/// ```
+	#[inline(always)]
+	fn tap_try_mut<E>(
+		mut self,
+		func: impl FnOnce(&mut Self) -> Result<(), E>,
+	) -> Result<Self, E> {
+		func(&mut self).map(|()| self)
+	}
+
 	//  debug-build-only copies of the above methods
 
 	/// Calls `.tap()` only in debug builds, and is erased in release builds.
+	///
+	/// The effect function and its captures are compiled out when
+	/// `debug_assertions` is off, so it must only contain work that the rest of
+	/// the program does not rely on, such as logging or assertions. The same
+	/// holds for every other `_dbg` method.
 	#[inline(always)]
-	fn tap_dbg(self, func: impl FnOnce(&Self)) -> Self {
+	fn tap_dbg<R>(self, func: impl FnOnce(&Self) -> R) -> Self {
 		if cfg!(debug_assertions) {
 			func(&self);
 		}
 		self
 	}
 
+	/// Calls `.tap_assert()` only in debug builds, and is erased in release
+	/// builds.
+	#[inline(always)]
+	#[track_caller]
+	fn tap_debug_assert(
+		self,
+		pred: impl FnOnce(&Self) -> bool,
+		msg: &str,
+	) -> Self {
+		if cfg!(debug_assertions) {
+			self.tap_assert(pred, msg)
+		} else {
+			self
+		}
+	}
+
 	/// Calls `.tap_mut()` only in debug builds, and is erased in release
 	/// builds.
 	#[inline(always)]
-	fn tap_mut_dbg(mut self, func: impl FnOnce(&mut Self)) -> Self {
+	fn tap_mut_dbg<R>(mut self, func: impl FnOnce(&mut Self) -> R) -> Self {
 		if cfg!(debug_assertions) {
 			func(&mut self);
 		}
@@ -324,6 +583,58 @@ where
 		}
 		self
 	}
+
+	/// Prints the value as pretty-printed JSON only in debug builds, and is
+	/// erased in release builds.
+	///
+	/// This method is only available when the `serde` feature is enabled. The
+	/// JSON is written to standard error, prefixed with the location of the
+	/// method call, in the same manner as `std::dbg!`. If the value fails to
+	/// serialize, the error is printed instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use serde::Serialize;
+	/// use tap::tap::Tap;
+	///
+	/// #[derive(Serialize)]
+	/// struct Config {
+	///   name: &'static str,
+	///   retries: u8,
+	/// }
+	///
+	/// let config = Config { name: "demo", retries: 3 }.tap_json_dbg();
+	/// assert_eq!(config.retries, 3);
+	/// ```
+	#[cfg(feature = "serde")]
+	#[inline(always)]
+	#[track_caller]
+	fn tap_json_dbg(self) -> Self
+	where
+		Self: serde::Serialize,
+	{
+		if cfg!(debug_assertions) {
+			let loc = Location::caller();
+			match serde_json::to_string_pretty(&self) {
+				Ok(json) => std::eprintln!(
+					"[{}:{}:{}] {}",
+					loc.file(),
+					loc.line(),
+					loc.column(),
+					json,
+				),
+				Err(err) => std::eprintln!(
+					"[{}:{}:{}] <failed to serialize: {}>",
+					loc.file(),
+					loc.line(),
+					loc.column(),
+					err,
+				),
+			}
+		}
+		self
+	}
 }
 
 impl<T> Tap for T where T: Sized {}
@@ -357,8 +668,28 @@ where
 	/// Implementors must not run the effect function if the container is marked
 	/// as being empty.
 	///
+	/// Like `.tap()`, the effect function may return any value, which is
+	/// discarded.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::TapOptional;
+	///
+	/// let mut hits = 0;
+	/// let mut record = |_: &i32| {
+	///   hits += 1;
+	///   hits
+	/// };
+	///
+	/// let found = Some(5).tap_some(&mut record);
+	/// let _ = None::<i32>.tap_some(&mut record);
+	/// assert_eq!(found, Some(5));
+	/// assert_eq!(hits, 1);
+	/// ```
+	///
 	/// [`Tap::tap`]: trait.Tap.html#method.tap
-	fn tap_some(self, func: impl FnOnce(&Self::Val)) -> Self;
+	fn tap_some<R>(self, func: impl FnOnce(&Self::Val) -> R) -> Self;
 
 	/// Mutably accesses an interor value only when it is present.
 	///
@@ -368,7 +699,7 @@ where
 	/// is marked as being empty.
 	///
 	/// [`Tap::tap_mut`]: trait.Tap.html#method.tap_mut
-	fn tap_some_mut(self, func: impl FnOnce(&mut Self::Val)) -> Self;
+	fn tap_some_mut<R>(self, func: impl FnOnce(&mut Self::Val) -> R) -> Self;
 
 	/// Runs an effect function when the container is empty.
 	///
@@ -377,13 +708,62 @@ where
 	/// Implementors must not run the effect function if the container is marked
 	/// as being non-empty.
 	///
+	/// Like `.tap()`, the effect function may return any value, which is
+	/// discarded. This lets a counter or metric function that reports its new
+	/// value be passed directly.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::TapOptional;
+	/// use std::collections::HashMap;
+	///
+	/// let mut misses = 0;
+	/// let mut count_miss = || {
+	///   misses += 1;
+	///   misses
+	/// };
+	///
+	/// let cache: HashMap<&str, i32> = HashMap::new();
+	/// let hit = cache.get("key").copied().tap_none(&mut count_miss);
+	/// assert_eq!(hit, None);
+	/// assert_eq!(misses, 1);
+	/// ```
+	///
 	/// [`Tap::tap`]: trait.Tap.html#method.tap
-	fn tap_none(self, func: impl FnOnce()) -> Self;
+	fn tap_none<R>(self, func: impl FnOnce() -> R) -> Self;
+
+	/// Runs `some` on an interior value when it is present, or `none` when the
+	/// container is empty.
+	///
+	/// This is equivalent to `.tap_some(some).tap_none(none)`. Exactly one of
+	/// the two effect functions runs.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::TapOptional;
+	/// # macro_rules! log { ($msg:literal $(, $x:ident)?) => {{}}; }
+	///
+	/// let found = [1, 2, 3]
+	///   .iter()
+	///   .find(|x| **x > 1)
+	///   .tap_some_or(|x| log!("found {}", x), || log!("missing"));
+	/// assert_eq!(found, Some(&2));
+	/// ```
+	#[inline(always)]
+	fn tap_some_or<R, S>(
+		self,
+		some: impl FnOnce(&Self::Val) -> R,
+		none: impl FnOnce() -> S,
+	) -> Self {
+		self.tap_some(some).tap_none(none)
+	}
 
 	/// Calls `.tap_some()` only in debug builds, and is erased in release
 	/// builds.
 	#[inline(always)]
-	fn tap_some_dbg(self, func: impl FnOnce(&Self::Val)) -> Self {
+	fn tap_some_dbg<R>(self, func: impl FnOnce(&Self::Val) -> R) -> Self {
 		if cfg!(debug_assertions) {
 			self.tap_some(func)
 		} else {
@@ -394,7 +774,10 @@ where
 	/// Calls `.tap_some_mut()` only in debug builds, and is erased in release
 	/// builds.
 	#[inline(always)]
-	fn tap_some_mut_dbg(self, func: impl FnOnce(&mut Self::Val)) -> Self {
+	fn tap_some_mut_dbg<R>(
+		self,
+		func: impl FnOnce(&mut Self::Val) -> R,
+	) -> Self {
 		if cfg!(debug_assertions) {
 			self.tap_some_mut(func)
 		} else {
@@ -405,20 +788,35 @@ where
 	/// Calls `.tap_none()` only in debug builds, and is erased in release
 	/// builds.
 	#[inline(always)]
-	fn tap_none_dbg(self, func: impl FnOnce()) -> Self {
+	fn tap_none_dbg<R>(self, func: impl FnOnce() -> R) -> Self {
 		if cfg!(debug_assertions) {
 			self.tap_none(func)
 		} else {
 			self
 		}
 	}
+
+	/// Calls `.tap_some_or()` only in debug builds, and is erased in release
+	/// builds.
+	#[inline(always)]
+	fn tap_some_or_dbg<R, S>(
+		self,
+		some: impl FnOnce(&Self::Val) -> R,
+		none: impl FnOnce() -> S,
+	) -> Self {
+		if cfg!(debug_assertions) {
+			self.tap_some_or(some, none)
+		} else {
+			self
+		}
+	}
 }
 
 impl<T> TapOptional for Option<T> {
 	type Val = T;
 
 	#[inline(always)]
-	fn tap_some(self, func: impl FnOnce(&T)) -> Self {
+	fn tap_some<R>(self, func: impl FnOnce(&T) -> R) -> Self {
 		if let Some(ref val) = self {
 			func(val);
 		}
@@ -426,7 +824,7 @@ impl<T> TapOptional for Option<T> {
 	}
 
 	#[inline(always)]
-	fn tap_some_mut(mut self, func: impl FnOnce(&mut T)) -> Self {
+	fn tap_some_mut<R>(mut self, func: impl FnOnce(&mut T) -> R) -> Self {
 		if let Some(ref mut val) = self {
 			func(val);
 		}
@@ -434,7 +832,7 @@ impl<T> TapOptional for Option<T> {
 	}
 
 	#[inline(always)]
-	fn tap_none(self, func: impl FnOnce()) -> Self {
+	fn tap_none<R>(self, func: impl FnOnce() -> R) -> Self {
 		if self.is_none() {
 			func();
 		}
@@ -473,7 +871,7 @@ where
 	/// as being a failure.
 	///
 	/// [`Tap::tap`]: trait.Tap.html#method.tap
-	fn tap_ok(self, func: impl FnOnce(&Self::Ok)) -> Self;
+	fn tap_ok<R>(self, func: impl FnOnce(&Self::Ok) -> R) -> Self;
 
 	/// Mutably accesses an interior success value.
 	///
@@ -483,7 +881,7 @@ where
 	/// is marked as being a failure.
 	///
 	/// [`Tap::tap_mut`]: trait.Tap.html#method.tap_mut
-	fn tap_ok_mut(self, func: impl FnOnce(&mut Self::Ok)) -> Self;
+	fn tap_ok_mut<R>(self, func: impl FnOnce(&mut Self::Ok) -> R) -> Self;
 
 	/// Immutably accesses an interior failure value.
 	///
@@ -492,8 +890,28 @@ where
 	/// Implementors must not run the effect function if the container is marked
 	/// as being a success.
 	///
+	/// # Examples
+	///
+	/// Here we use `.tap_err()` to log a parse failure before the `?` operator
+	/// propagates it.
+	///
+	/// ```rust
+	/// use tap::tap::TapFallible;
+	/// # macro_rules! error { ($msg:literal, $x:ident) => {{}}; }
+	///
+	/// fn parse(text: &str) -> Result<i32, std::num::ParseIntError> {
+	///   let num = text
+	///     .parse::<i32>()
+	///     .tap_err(|e| error!("could not parse the input: {}", e))?;
+	///   Ok(num * 2)
+	/// }
+	///
+	/// assert_eq!(parse("21"), Ok(42));
+	/// assert!(parse("twenty-one").is_err());
+	/// ```
+	///
 	/// [`Tap::tap`]: trait.Tap.html#method.tap
-	fn tap_err(self, func: impl FnOnce(&Self::Err)) -> Self;
+	fn tap_err<R>(self, func: impl FnOnce(&Self::Err) -> R) -> Self;
 
 	/// Mutably accesses an interior failure value.
 	///
@@ -503,11 +921,48 @@ where
 	/// is marked as being a success.
 	///
 	/// [`Tap::tap_mut`]: trait.Tap.html#method.tap_mut
-	fn tap_err_mut(self, func: impl FnOnce(&mut Self::Err)) -> Self;
+	fn tap_err_mut<R>(self, func: impl FnOnce(&mut Self::Err) -> R) -> Self;
+
+	/// Immutably accesses an interior failure value, along with context that
+	/// is only computed on failure.
+	///
+	/// This function is identical to [`TapFallible::tap_err`], except that
+	/// `ctx` is evaluated, and its result passed to the effect function
+	/// alongside the error, only when the container is a failure. Expensive
+	/// context is therefore never computed on the success path.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::TapFallible;
+	/// # macro_rules! error { ($msg:literal, $($x:ident),*) => {{}}; }
+	///
+	/// fn load(path: &str) -> Result<u16, std::num::ParseIntError> {
+	///   let port = "eighty"
+	///     .parse::<u16>()
+	///     .tap_err_with(
+	///       || format!("while loading {}", path),
+	///       |e, ctx| error!("{}: {}", ctx, e),
+	///     )?;
+	///   Ok(port)
+	/// }
+	///
+	/// assert!(load("server.toml").is_err());
+	/// ```
+	///
+	/// [`TapFallible::tap_err`]: trait.TapFallible.html#method.tap_err
+	#[inline(always)]
+	fn tap_err_with<C, R>(
+		self,
+		ctx: impl FnOnce() -> C,
+		func: impl FnOnce(&Self::Err, C) -> R,
+	) -> Self {
+		self.tap_err(|err| func(err, ctx()))
+	}
 
 	/// Calls `.tap_ok()` only in debug builds, and is erased in release builds.
 	#[inline(always)]
-	fn tap_ok_dbg(self, func: impl FnOnce(&Self::Ok)) -> Self {
+	fn tap_ok_dbg<R>(self, func: impl FnOnce(&Self::Ok) -> R) -> Self {
 		if cfg!(debug_assertions) {
 			self.tap_ok(func)
 		} else {
@@ -518,7 +973,7 @@ where
 	/// Calls `.tap_ok_mut()` only in debug builds, and is erased in release
 	/// builds.
 	#[inline(always)]
-	fn tap_ok_mut_dbg(self, func: impl FnOnce(&mut Self::Ok)) -> Self {
+	fn tap_ok_mut_dbg<R>(self, func: impl FnOnce(&mut Self::Ok) -> R) -> Self {
 		if cfg!(debug_assertions) {
 			self.tap_ok_mut(func)
 		} else {
@@ -529,7 +984,7 @@ where
 	/// Calls `.tap_err()` only in debug builds, and is erased in release
 	/// builds.
 	#[inline(always)]
-	fn tap_err_dbg(self, func: impl FnOnce(&Self::Err)) -> Self {
+	fn tap_err_dbg<R>(self, func: impl FnOnce(&Self::Err) -> R) -> Self {
 		if cfg!(debug_assertions) {
 			self.tap_err(func)
 		} else {
@@ -540,13 +995,28 @@ where
 	/// Calls `.tap_err_mut()` only in debug builds, and is erased in release
 	/// builds.
 	#[inline(always)]
-	fn tap_err_mut_dbg(self, func: impl FnOnce(&mut Self::Err)) -> Self {
+	fn tap_err_mut_dbg<R>(self, func: impl FnOnce(&mut Self::Err) -> R) -> Self {
 		if cfg!(debug_assertions) {
 			self.tap_err_mut(func)
 		} else {
 			self
 		}
 	}
+
+	/// Calls `.tap_err_with()` only in debug builds, and is erased in release
+	/// builds.
+	#[inline(always)]
+	fn tap_err_with_dbg<C, R>(
+		self,
+		ctx: impl FnOnce() -> C,
+		func: impl FnOnce(&Self::Err, C) -> R,
+	) -> Self {
+		if cfg!(debug_assertions) {
+			self.tap_err_with(ctx, func)
+		} else {
+			self
+		}
+	}
 }
 
 impl<T, E> TapFallible for Result<T, E> {
@@ -554,7 +1024,7 @@ impl<T, E> TapFallible for Result<T, E> {
 	type Err = E;
 
 	#[inline(always)]
-	fn tap_ok(self, func: impl FnOnce(&T)) -> Self {
+	fn tap_ok<R>(self, func: impl FnOnce(&T) -> R) -> Self {
 		if let Ok(ref val) = self {
 			func(val);
 		}
@@ -562,7 +1032,7 @@ impl<T, E> TapFallible for Result<T, E> {
 	}
 
 	#[inline(always)]
-	fn tap_ok_mut(mut self, func: impl FnOnce(&mut T)) -> Self {
+	fn tap_ok_mut<R>(mut self, func: impl FnOnce(&mut T) -> R) -> Self {
 		if let Ok(ref mut val) = self {
 			func(val);
 		}
@@ -570,7 +1040,7 @@ impl<T, E> TapFallible for Result<T, E> {
 	}
 
 	#[inline(always)]
-	fn tap_err(self, func: impl FnOnce(&E)) -> Self {
+	fn tap_err<R>(self, func: impl FnOnce(&E) -> R) -> Self {
 		if let Err(ref val) = self {
 			func(val);
 		}
@@ -578,10 +1048,819 @@ impl<T, E> TapFallible for Result<T, E> {
 	}
 
 	#[inline(always)]
-	fn tap_err_mut(mut self, func: impl FnOnce(&mut E)) -> Self {
+	fn tap_err_mut<R>(mut self, func: impl FnOnce(&mut E) -> R) -> Self {
 		if let Err(ref mut val) = self {
 			func(val);
 		}
 		self
 	}
 }
+
+/** Flattening taps, for results nested inside results.
+
+This trait is intended for `Result<Result<T, E>, E>`, which appears when a
+fallible step produces another fallible value that shares its error type. It
+flattens the two levels into one `Result<T, E>`, and inspects the error on the
+way out, wherever it came from.
+**/
+pub trait TapFlatten
+where
+	Self: Sized,
+{
+	/// The interior type used to indicate a successful construction.
+	type Ok;
+
+	/// The interior type used to indicate a failed construction, at either
+	/// level.
+	type Err;
+
+	/// Flattens a nested result, and immutably accesses the error if there is
+	/// one.
+	///
+	/// The effect function runs exactly once when either the outer or the
+	/// inner level is an error, and not at all on success.
+	///
+	/// # Examples
+	///
+	/// An outer error is passed through and inspected:
+	///
+	/// ```rust
+	/// use tap::tap::TapFlatten;
+	///
+	/// let mut logged = Vec::new();
+	/// let nested: Result<Result<u8, &str>, &str> = Err("connect failed");
+	/// let res = nested.tap_and_flatten(|e| logged.push(*e));
+	/// assert_eq!(res, Err("connect failed"));
+	/// assert_eq!(logged, ["connect failed"]);
+	/// ```
+	///
+	/// and so is an inner one:
+	///
+	/// ```rust
+	/// use tap::tap::TapFlatten;
+	///
+	/// let mut logged = Vec::new();
+	/// let nested: Result<Result<u8, &str>, &str> = Ok(Err("bad reply"));
+	/// let res = nested.tap_and_flatten(|e| logged.push(*e));
+	/// assert_eq!(res, Err("bad reply"));
+	/// assert_eq!(logged, ["bad reply"]);
+	/// ```
+	fn tap_and_flatten<R>(
+		self,
+		func: impl FnOnce(&Self::Err) -> R,
+	) -> Result<Self::Ok, Self::Err>;
+
+	/// Flattens a nested result, and calls `func` on the error only in debug
+	/// builds.
+	///
+	/// The result is flattened in every build; only the tap is erased in
+	/// release builds.
+	#[inline(always)]
+	fn tap_and_flatten_dbg<R>(
+		self,
+		func: impl FnOnce(&Self::Err) -> R,
+	) -> Result<Self::Ok, Self::Err> {
+		if cfg!(debug_assertions) {
+			self.tap_and_flatten(func)
+		} else {
+			self.tap_and_flatten(|_| ())
+		}
+	}
+}
+
+impl<T, E> TapFlatten for Result<Result<T, E>, E> {
+	type Ok = T;
+	type Err = E;
+
+	#[inline(always)]
+	fn tap_and_flatten<R>(self, func: impl FnOnce(&E) -> R) -> Result<T, E> {
+		self.and_then(|inner| inner).tap_err(func)
+	}
+}
+
+/** Early-exit tapping, conditional on the state of a `ControlFlow`.
+
+This trait is intended for use on [`ControlFlow`], which visitors and
+short-circuiting iteration use to report whether to keep going or to stop. It
+provides taps that inspect the value to determine if the effect function should
+execute or not, in the same manner as [`TapFallible`].
+
+[`ControlFlow`]: https://doc.rust-lang.org/core/ops/enum.ControlFlow.html
+[`TapFallible`]: trait.TapFallible.html
+**/
+pub trait TapControlFlow
+where
+	Self: Sized,
+{
+	/// The interior type carried when the operation should continue.
+	type Continue: ?Sized;
+
+	/// The interior type carried when the operation should exit early.
+	type Break: ?Sized;
+
+	/// Immutably accesses an interior continuation value.
+	///
+	/// This function is identical to [`Tap::tap`], except that it is required
+	/// to check the implementing container for continuation before running.
+	/// Implementors must not run the effect function if the container is marked
+	/// as breaking.
+	///
+	/// [`Tap::tap`]: trait.Tap.html#method.tap
+	fn tap_continue<R>(self, func: impl FnOnce(&Self::Continue) -> R) -> Self;
+
+	/// Mutably accesses an interior continuation value.
+	///
+	/// This function is identical to [`Tap::tap_mut`], except that it is
+	/// required to check the implementing container for continuation before
+	/// running. Implementors must not run the effect function if the container
+	/// is marked as breaking.
+	///
+	/// [`Tap::tap_mut`]: trait.Tap.html#method.tap_mut
+	fn tap_continue_mut<R>(
+		self,
+		func: impl FnOnce(&mut Self::Continue) -> R,
+	) -> Self;
+
+	/// Immutably accesses an interior break value.
+	///
+	/// This function is identical to [`Tap::tap`], except that it is required
+	/// to check the implementing container for an early exit before running.
+	/// Implementors must not run the effect function if the container is marked
+	/// as continuing.
+	///
+	/// # Examples
+	///
+	/// Here we use `.tap_break()` to log the item that stopped a `try_fold`.
+	///
+	/// ```rust
+	/// use std::ops::ControlFlow;
+	/// use tap::tap::TapControlFlow;
+	/// # macro_rules! log { ($msg:literal, $x:ident) => {{}}; }
+	///
+	/// let total = [1, 2, 30, 4].iter().try_fold(0, |acc, &x| {
+	///   if x > 10 {
+	///     ControlFlow::Break(x)
+	///   } else {
+	///     ControlFlow::Continue(acc + x)
+	///   }
+	///   .tap_break(|x| log!("stopped at {}", x))
+	/// });
+	/// assert_eq!(total, ControlFlow::Break(30));
+	/// ```
+	///
+	/// [`Tap::tap`]: trait.Tap.html#method.tap
+	fn tap_break<R>(self, func: impl FnOnce(&Self::Break) -> R) -> Self;
+
+	/// Mutably accesses an interior break value.
+	///
+	/// This function is identical to [`Tap::tap_mut`], except that it is
+	/// required to check the implementing container for an early exit before
+	/// running. Implementors must not run the effect function if the container
+	/// is marked as continuing.
+	///
+	/// [`Tap::tap_mut`]: trait.Tap.html#method.tap_mut
+	fn tap_break_mut<R>(self, func: impl FnOnce(&mut Self::Break) -> R) -> Self;
+
+	/// Calls `.tap_continue()` only in debug builds, and is erased in release
+	/// builds.
+	#[inline(always)]
+	fn tap_continue_dbg<R>(
+		self,
+		func: impl FnOnce(&Self::Continue) -> R,
+	) -> Self {
+		if cfg!(debug_assertions) {
+			self.tap_continue(func)
+		} else {
+			self
+		}
+	}
+
+	/// Calls `.tap_continue_mut()` only in debug builds, and is erased in
+	/// release builds.
+	#[inline(always)]
+	fn tap_continue_mut_dbg<R>(
+		self,
+		func: impl FnOnce(&mut Self::Continue) -> R,
+	) -> Self {
+		if cfg!(debug_assertions) {
+			self.tap_continue_mut(func)
+		} else {
+			self
+		}
+	}
+
+	/// Calls `.tap_break()` only in debug builds, and is erased in release
+	/// builds.
+	#[inline(always)]
+	fn tap_break_dbg<R>(self, func: impl FnOnce(&Self::Break) -> R) -> Self {
+		if cfg!(debug_assertions) {
+			self.tap_break(func)
+		} else {
+			self
+		}
+	}
+
+	/// Calls `.tap_break_mut()` only in debug builds, and is erased in release
+	/// builds.
+	#[inline(always)]
+	fn tap_break_mut_dbg<R>(
+		self,
+		func: impl FnOnce(&mut Self::Break) -> R,
+	) -> Self {
+		if cfg!(debug_assertions) {
+			self.tap_break_mut(func)
+		} else {
+			self
+		}
+	}
+}
+
+impl<B, C> TapControlFlow for ControlFlow<B, C> {
+	type Continue = C;
+	type Break = B;
+
+	#[inline(always)]
+	fn tap_continue<R>(self, func: impl FnOnce(&C) -> R) -> Self {
+		if let ControlFlow::Continue(ref val) = self {
+			func(val);
+		}
+		self
+	}
+
+	#[inline(always)]
+	fn tap_continue_mut<R>(mut self, func: impl FnOnce(&mut C) -> R) -> Self {
+		if let ControlFlow::Continue(ref mut val) = self {
+			func(val);
+		}
+		self
+	}
+
+	#[inline(always)]
+	fn tap_break<R>(self, func: impl FnOnce(&B) -> R) -> Self {
+		if let ControlFlow::Break(ref val) = self {
+			func(val);
+		}
+		self
+	}
+
+	#[inline(always)]
+	fn tap_break_mut<R>(mut self, func: impl FnOnce(&mut B) -> R) -> Self {
+		if let ControlFlow::Break(ref mut val) = self {
+			func(val);
+		}
+		self
+	}
+}
+
+/** Per-element tapping, for iterator pipelines.
+
+This trait provides adapters that run an effect function on each item of an
+iterator as it is yielded, without otherwise changing the sequence. Like all
+iterator adapters, they are lazy: nothing runs until the iterator is advanced.
+
+`.tap_each()` is equivalent to [`Iterator::inspect`], and is provided so that
+iterator pipelines can use the same vocabulary as the rest of this module.
+`.tap_each_mut()` has no standard-library equivalent, as `inspect` cannot modify
+the items it views.
+
+[`Iterator::inspect`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.inspect
+**/
+pub trait TapIterator
+where
+	Self: Iterator + Sized,
+{
+	/// Immutable access to each item of an iterator.
+	///
+	/// This function is identical to [`Tap::tap`], except that it is applied
+	/// to every item yielded by the iterator, rather than to the iterator
+	/// itself.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::TapIterator;
+	///
+	/// let mut seen = vec![];
+	/// let sum: i32 = [1, 2, 3]
+	///   .iter()
+	///   .tap_each(|x| seen.push(**x))
+	///   .sum();
+	/// assert_eq!(sum, 6);
+	/// assert_eq!(seen, [1, 2, 3]);
+	/// ```
+	///
+	/// [`Tap::tap`]: trait.Tap.html#method.tap
+	#[inline(always)]
+	fn tap_each<F>(self, func: F) -> TapEach<Self, F>
+	where
+		F: FnMut(&Self::Item),
+	{
+		TapEach { iter: self, func }
+	}
+
+	/// Mutable access to each item of an iterator.
+	///
+	/// This function is identical to [`Tap::tap_mut`], except that it is
+	/// applied to every item yielded by the iterator, rather than to the
+	/// iterator itself. Each item is yielded after the effect function has
+	/// modified it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::TapIterator;
+	///
+	/// let names = ["alice", "bob"]
+	///   .iter()
+	///   .map(|s| s.to_string())
+	///   .tap_each_mut(|s| s.make_ascii_uppercase())
+	///   .collect::<Vec<_>>();
+	/// assert_eq!(names, ["ALICE", "BOB"]);
+	/// ```
+	///
+	/// [`Tap::tap_mut`]: trait.Tap.html#method.tap_mut
+	#[inline(always)]
+	fn tap_each_mut<F>(self, func: F) -> TapEachMut<Self, F>
+	where
+		F: FnMut(&mut Self::Item),
+	{
+		TapEachMut { iter: self, func }
+	}
+}
+
+impl<I> TapIterator for I where I: Iterator {}
+
+/** An iterator that taps each item before yielding it.
+
+This is created by [`TapIterator::tap_each`].
+
+[`TapIterator::tap_each`]: trait.TapIterator.html#method.tap_each
+**/
+#[derive(Clone)]
+#[must_use = "iterator adapters are lazy, and do nothing unless consumed"]
+pub struct TapEach<I, F> {
+	iter: I,
+	func: F,
+}
+
+impl<I, F> Iterator for TapEach<I, F>
+where
+	I: Iterator,
+	F: FnMut(&I::Item),
+{
+	type Item = I::Item;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let item = self.iter.next()?;
+		(self.func)(&item);
+		Some(item)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
+
+impl<I, F> DoubleEndedIterator for TapEach<I, F>
+where
+	I: DoubleEndedIterator,
+	F: FnMut(&I::Item),
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let item = self.iter.next_back()?;
+		(self.func)(&item);
+		Some(item)
+	}
+}
+
+impl<I, F> ExactSizeIterator for TapEach<I, F>
+where
+	I: ExactSizeIterator,
+	F: FnMut(&I::Item),
+{
+}
+
+impl<I, F> FusedIterator for TapEach<I, F>
+where
+	I: FusedIterator,
+	F: FnMut(&I::Item),
+{
+}
+
+/** An iterator that mutably taps each item before yielding it.
+
+This is created by [`TapIterator::tap_each_mut`].
+
+[`TapIterator::tap_each_mut`]: trait.TapIterator.html#method.tap_each_mut
+**/
+#[derive(Clone)]
+#[must_use = "iterator adapters are lazy, and do nothing unless consumed"]
+pub struct TapEachMut<I, F> {
+	iter: I,
+	func: F,
+}
+
+impl<I, F> Iterator for TapEachMut<I, F>
+where
+	I: Iterator,
+	F: FnMut(&mut I::Item),
+{
+	type Item = I::Item;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let mut item = self.iter.next()?;
+		(self.func)(&mut item);
+		Some(item)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.iter.size_hint()
+	}
+}
+
+impl<I, F> DoubleEndedIterator for TapEachMut<I, F>
+where
+	I: DoubleEndedIterator,
+	F: FnMut(&mut I::Item),
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let mut item = self.iter.next_back()?;
+		(self.func)(&mut item);
+		Some(item)
+	}
+}
+
+impl<I, F> ExactSizeIterator for TapEachMut<I, F>
+where
+	I: ExactSizeIterator,
+	F: FnMut(&mut I::Item),
+{
+}
+
+impl<I, F> FusedIterator for TapEachMut<I, F>
+where
+	I: FusedIterator,
+	F: FnMut(&mut I::Item),
+{
+}
+
+/** A value with a list of pending taps, applied in order by [`Tapped::run`].
+
+This type is only available when the `alloc` feature is enabled.
+
+The extension-method taps run immediately. `Tapped` instead stores each effect
+function as a boxed closure, so that a value and its inspection pipeline can be
+built in one place, passed around or extended elsewhere, and run later. The
+effects run in the order they were added, each seeing the changes of the ones
+before it.
+
+# Examples
+
+```rust
+use tap::tap::Tapped;
+
+fn with_defaults(name: &str) -> Tapped<'_, Vec<String>> {
+  let pending = Tapped::new(Vec::new());
+  let pending = pending.tap_mut(move |v| v.push(name.to_string()));
+  pending.tap_mut(|v| v.push("default".to_string()))
+}
+
+let names = with_defaults("custom")
+  .tap(|v| assert_eq!(v.len(), 2))
+  .run();
+assert_eq!(names, ["custom", "default"]);
+```
+
+[`Tapped::run`]: struct.Tapped.html#method.run
+**/
+#[cfg(feature = "alloc")]
+#[must_use = "pending taps do nothing unless `.run()` is called"]
+pub struct Tapped<'a, T> {
+	value: T,
+	taps: Vec<PendingTap<'a, T>>,
+}
+
+/// A stored effect function, as held by [`Tapped`].
+#[cfg(feature = "alloc")]
+type PendingTap<'a, T> = Box<dyn FnOnce(&mut T) + 'a>;
+
+#[cfg(feature = "alloc")]
+impl<'a, T> Tapped<'a, T> {
+	/// Wraps a value, with no pending taps.
+	#[inline(always)]
+	pub fn new(value: T) -> Self {
+		Self {
+			value,
+			taps: Vec::new(),
+		}
+	}
+
+	/// Adds an immutable tap, which runs when the pipeline is run.
+	///
+	/// As with [`Tap::tap`], any value the effect function returns is
+	/// discarded.
+	///
+	/// [`Tap::tap`]: trait.Tap.html#method.tap
+	#[inline(always)]
+	pub fn tap<R>(self, func: impl FnOnce(&T) -> R + 'a) -> Self {
+		self.tap_mut(move |val| {
+			func(val);
+		})
+	}
+
+	/// Adds a mutable tap, which runs when the pipeline is run.
+	///
+	/// As with [`Tap::tap_mut`], any value the effect function returns is
+	/// discarded.
+	///
+	/// [`Tap::tap_mut`]: trait.Tap.html#method.tap_mut
+	#[inline(always)]
+	pub fn tap_mut<R>(mut self, func: impl FnOnce(&mut T) -> R + 'a) -> Self {
+		self.taps.push(Box::new(move |val| {
+			func(val);
+		}));
+		self
+	}
+
+	/// Runs every pending tap in the order it was added, then returns the
+	/// value.
+	#[inline(always)]
+	pub fn run(self) -> T {
+		let Self { mut value, taps } = self;
+		for tap in taps {
+			tap(&mut value);
+		}
+		value
+	}
+}
+
+/** Point-free inspection and modification with asynchronous effects.
+
+This trait is only available when the `async` feature is enabled.
+
+Its methods are identical to [`Tap::tap`] and [`Tap::tap_mut`], except that
+the effect function is asynchronous, and is awaited before the value is
+returned. The effect function’s borrow of the value lasts for the whole future,
+so an `async fn` taking `&T` can be passed directly.
+
+[`Tap::tap`]: trait.Tap.html#method.tap
+[`Tap::tap_mut`]: trait.Tap.html#method.tap_mut
+**/
+#[cfg(feature = "async")]
+pub trait AsyncTap
+where
+	Self: Sized,
+{
+	/// Immutable access to a value, followed by awaiting an effect.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::AsyncTap;
+	/// # fn block_on<F: core::future::Future>(fut: F) -> F::Output {
+	/// #   use core::task::{Context, Poll, Waker};
+	/// #   let mut fut = core::pin::pin!(fut);
+	/// #   let mut cx = Context::from_waker(Waker::noop());
+	/// #   loop {
+	/// #     if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+	/// #       return out;
+	/// #     }
+	/// #   }
+	/// # }
+	/// # async fn record_metric(_: usize) {}
+	///
+	/// let batch = block_on(async {
+	///   vec![1, 2, 3]
+	///     .tap_async(async |v| record_metric(v.len()).await)
+	///     .await
+	/// });
+	/// assert_eq!(batch, [1, 2, 3]);
+	/// ```
+	///
+	/// An `async fn` that borrows the value can be passed directly:
+	///
+	/// ```rust
+	/// use tap::tap::AsyncTap;
+	/// # fn block_on<F: core::future::Future>(fut: F) -> F::Output {
+	/// #   use core::task::{Context, Poll, Waker};
+	/// #   let mut fut = core::pin::pin!(fut);
+	/// #   let mut cx = Context::from_waker(Waker::noop());
+	/// #   loop {
+	/// #     if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+	/// #       return out;
+	/// #     }
+	/// #   }
+	/// # }
+	///
+	/// async fn write_metric(batch: &Vec<u8>) {
+	///   assert_eq!(batch.len(), 3);
+	/// }
+	///
+	/// let batch = block_on(async {
+	///   vec![1u8, 2, 3].tap_async(write_metric).await
+	/// });
+	/// assert_eq!(batch, [1, 2, 3]);
+	/// ```
+	#[inline(always)]
+	fn tap_async<F>(self, func: F) -> impl Future<Output = Self>
+	where
+		F: AsyncFnOnce(&Self),
+	{
+		async move {
+			func(&self).await;
+			self
+		}
+	}
+
+	/// Mutable access to a value, followed by awaiting an effect.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::AsyncTap;
+	/// # fn block_on<F: core::future::Future>(fut: F) -> F::Output {
+	/// #   use core::task::{Context, Poll, Waker};
+	/// #   let mut fut = core::pin::pin!(fut);
+	/// #   let mut cx = Context::from_waker(Waker::noop());
+	/// #   loop {
+	/// #     if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+	/// #       return out;
+	/// #     }
+	/// #   }
+	/// # }
+	///
+	/// let batch = block_on(async {
+	///   vec![3, 1, 2]
+	///     .tap_mut_async(async |v| {
+	///       v.sort();
+	///       assert_eq!(v[0], 1);
+	///     })
+	///     .await
+	/// });
+	/// assert_eq!(batch, [1, 2, 3]);
+	///
+	/// async fn append_total(batch: &mut Vec<u8>) {
+	///   let total = batch.iter().sum();
+	///   batch.push(total);
+	/// }
+	///
+	/// let batch = block_on(batch.tap_mut_async(append_total));
+	/// assert_eq!(batch, [1, 2, 3, 6]);
+	/// ```
+	#[inline(always)]
+	fn tap_mut_async<F>(mut self, func: F) -> impl Future<Output = Self>
+	where
+		F: AsyncFnOnce(&mut Self),
+	{
+		async move {
+			func(&mut self).await;
+			self
+		}
+	}
+}
+
+#[cfg(feature = "async")]
+impl<T> AsyncTap for T where T: Sized {}
+
+/// Emits a `tracing` event at a level chosen at runtime.
+///
+/// `tracing` requires event levels to be constant, so each level gets its own
+/// callsite. The caller's location is recorded as fields, because the event
+/// metadata can only describe this file.
+#[cfg(feature = "tracing")]
+macro_rules! trace_event {
+	($level:expr, $loc:expr, $val:expr, $msg:expr) => {
+		match $level {
+			Level::ERROR => trace_event!(@ Level::ERROR, $loc, $val, $msg),
+			Level::WARN => trace_event!(@ Level::WARN, $loc, $val, $msg),
+			Level::INFO => trace_event!(@ Level::INFO, $loc, $val, $msg),
+			Level::DEBUG => trace_event!(@ Level::DEBUG, $loc, $val, $msg),
+			Level::TRACE => trace_event!(@ Level::TRACE, $loc, $val, $msg),
+		}
+	};
+	(@ $level:expr, $loc:expr, $val:expr, $msg:expr) => {
+		tracing::event!(
+			$level,
+			value = ?$val,
+			caller.file = $loc.file(),
+			caller.line = $loc.line(),
+			"{}",
+			$msg,
+		)
+	};
+}
+
+/** Point-free value inspection through `tracing` events.
+
+This trait is only available when the `tracing` feature is enabled.
+
+Its methods emit an event carrying the `Debug` rendering of the value in the
+`value` field, and the location of the method call in the `caller.file` and
+`caller.line` fields, then return the value unchanged.
+
+The events are emitted from inside this crate, so their target and module path
+are `tap::tap`, not the module that calls the method. A filter that enables only
+your own crate, such as `RUST_LOG=myapp=debug`, drops them; add a `tap=trace`
+directive, or a lower level, to receive them.
+**/
+#[cfg(feature = "tracing")]
+pub trait TapTrace
+where
+	Self: Sized,
+{
+	/// Emits an event at `level`, containing `message` and the value.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::TapTrace;
+	/// use tracing::Level;
+	///
+	/// let sum = [1, 2, 3]
+	///   .iter()
+	///   .sum::<i32>()
+	///   .tap_trace(Level::DEBUG, "computed the sum");
+	/// assert_eq!(sum, 6);
+	/// ```
+	#[inline(always)]
+	#[track_caller]
+	fn tap_trace(self, level: Level, message: &str) -> Self
+	where
+		Self: Debug,
+	{
+		trace_event!(level, Location::caller(), self, message);
+		self
+	}
+}
+
+#[cfg(feature = "tracing")]
+impl<T> TapTrace for T where T: Sized {}
+
+/** Error-only inspection through `tracing` events.
+
+This trait is only available when the `tracing` feature is enabled.
+
+Its method emits an event at the `ERROR` level only when the container holds its
+failure variant, and does nothing otherwise. The event fields match those of
+[`TapTrace`], and so does the event target: it is `tap::tap`, so filters must
+enable the `tap` target, for example with `tap=error`.
+
+[`TapTrace`]: trait.TapTrace.html
+**/
+#[cfg(feature = "tracing")]
+pub trait TapErrTrace
+where
+	Self: Sized,
+{
+	/// Emits an `ERROR` event containing `message` when `self` is a failure.
+	///
+	/// For `Result`, the `value` field holds the `Debug` rendering of the
+	/// error. For `Option`, it holds `None`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use tap::tap::TapErrTrace;
+	///
+	/// let port = "eighty"
+	///   .parse::<u16>()
+	///   .tap_err_trace("invalid port")
+	///   .unwrap_or(80);
+	/// assert_eq!(port, 80);
+	/// ```
+	#[track_caller]
+	fn tap_err_trace(self, message: &str) -> Self;
+}
+
+#[cfg(feature = "tracing")]
+impl<T, E> TapErrTrace for Result<T, E>
+where
+	E: Debug,
+{
+	#[inline(always)]
+	#[track_caller]
+	fn tap_err_trace(self, message: &str) -> Self {
+		if let Err(ref err) = self {
+			trace_event!(@ Level::ERROR, Location::caller(), err, message);
+		}
+		self
+	}
+}
+
+#[cfg(feature = "tracing")]
+impl<T> TapErrTrace for Option<T> {
+	#[inline(always)]
+	#[track_caller]
+	fn tap_err_trace(self, message: &str) -> Self {
+		if self.is_none() {
+			trace_event!(@ Level::ERROR, Location::caller(), None::<()>, message);
+		}
+		self
+	}
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "std")]
+
+use std::panic;
+use tap::prelude::*;
+
+#[test]
+fn pipe_catch_unwind_returns_output() {
+	let out = "21".pipe_catch_unwind(|s| s.parse::<i32>().unwrap() * 2);
+	assert_eq!(out.unwrap(), 42);
+}
+
+#[test]
+fn pipe_catch_unwind_catches_panic() {
+	// Keep the expected panic message out of the test output.
+	panic::set_hook(Box::new(|_| {}));
+	let out =
+		"nope".pipe_catch_unwind(|s| s.parse::<i32>().expect("not a number"));
+	let _ = panic::take_hook();
+
+	let payload = out.unwrap_err();
+	let msg = payload.downcast_ref::<String>().unwrap();
+	assert!(msg.starts_with("not a number"), "{}", msg);
+}
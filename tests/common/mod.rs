@@ -0,0 +1,27 @@
+//! Helpers shared between the integration-test binaries.
+
+use std::{env, process::Command};
+
+/// Reports whether this process is the child started by [`child_stderr`].
+///
+/// A test that checks its own standard error first calls this. In the child it
+/// produces the output under test and returns; in the parent it calls
+/// `child_stderr` instead.
+pub fn is_child(env_key: &str) -> bool {
+	env::var_os(env_key).is_some()
+}
+
+/// Runs `test_name` again in a child process, with `env_key` set, and returns
+/// what it wrote to standard error.
+///
+/// Standard error cannot be captured in-process, so this re-runs the current
+/// test binary filtered to the one test. Panics if the child test fails.
+pub fn child_stderr(test_name: &str, env_key: &str) -> String {
+	let out = Command::new(env::current_exe().unwrap())
+		.args([test_name, "--exact", "--nocapture"])
+		.env(env_key, "1")
+		.output()
+		.unwrap();
+	assert!(out.status.success(), "child test `{}` failed", test_name);
+	String::from_utf8(out.stderr).unwrap()
+}
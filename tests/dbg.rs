@@ -0,0 +1,42 @@
+#![cfg(all(feature = "std", debug_assertions))]
+
+use tap::prelude::*;
+
+mod common;
+
+#[derive(Debug)]
+struct Point {
+	x: i32,
+	y: i32,
+}
+
+const CHILD: &str = "TAP_PIPE_DBG_CHILD";
+
+#[test]
+fn pipe_dbg_matches_dbg_format() {
+	if common::is_child(CHILD) {
+		let line = line!() + 1;
+		let point = Point { x: 1, y: 2 }.pipe_dbg();
+		assert_eq!(point.x + point.y, 3);
+		eprintln!("line={}", line);
+		return;
+	}
+
+	let stderr = common::child_stderr("pipe_dbg_matches_dbg_format", CHILD);
+	let line = stderr
+		.lines()
+		.find_map(|l| l.strip_prefix("line="))
+		.unwrap();
+	let prefix = format!("[{}:{}:", file!(), line);
+	let start = stderr.find(&prefix).unwrap();
+	let (location, value) =
+		stderr[start..].split_at(stderr[start..].find("] ").unwrap() + 2);
+	assert!(location[prefix.len()..location.len() - 2]
+		.parse::<u32>()
+		.is_ok());
+	assert!(
+		value.starts_with("Point {\n    x: 1,\n    y: 2,\n}\n"),
+		"{}",
+		value
+	);
+}
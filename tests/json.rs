@@ -0,0 +1,40 @@
+#![cfg(all(feature = "serde", debug_assertions))]
+
+use serde::Serialize;
+use tap::prelude::*;
+
+mod common;
+
+#[derive(Serialize)]
+struct Config {
+	name: &'static str,
+	retries: u8,
+	tags: Vec<&'static str>,
+}
+
+const CHILD: &str = "TAP_JSON_DBG_CHILD";
+
+#[test]
+fn tap_json_dbg_prints_json() {
+	if common::is_child(CHILD) {
+		let config = Config {
+			name: "demo",
+			retries: 3,
+			tags: vec!["a", "b"],
+		}
+		.tap_json_dbg();
+		assert_eq!(config.retries, 3);
+		return;
+	}
+
+	let stderr = common::child_stderr("tap_json_dbg_prints_json", CHILD);
+	let line = stderr.find('[').map(|start| &stderr[start..]).unwrap();
+	let (prefix, json) = line.split_at(line.find("] ").unwrap() + 2);
+	assert!(prefix.starts_with(&format!("[{}:", file!())), "{}", prefix);
+
+	let value: serde_json::Value = serde_json::from_str(json).unwrap();
+	assert_eq!(
+		value,
+		serde_json::json!({ "name": "demo", "retries": 3, "tags": ["a", "b"] }),
+	);
+}
@@ -31,3 +31,429 @@ fn basic() {
 	let _: Option<i32> = None.tap_none(|| val = 10);
 	assert_eq!(val, 10);
 }
+
+#[test]
+fn tap_preserves_value() {
+	use std::cell::Cell;
+
+	// Neither `Clone` nor `Copy`: the tapped value is moved through, not
+	// duplicated.
+	struct Counter(Cell<u32>);
+
+	let counter = Counter(Cell::new(0))
+		.tap(|c| c.0.set(c.0.get() + 1))
+		.tap(|c| c.0.set(c.0.get() + 1));
+	assert_eq!(counter.0.get(), 2);
+
+	let counter = counter.tap_mut(|c| *c.0.get_mut() *= 10);
+	assert_eq!(counter.0.get(), 20);
+}
+
+#[test]
+fn tap_optional() {
+	let mut seen = None;
+	let mut empty = false;
+
+	let some = Some(5)
+		.tap_some(|v| seen = Some(*v))
+		.tap_none(|| empty = true);
+	assert_eq!(some, Some(5));
+	assert_eq!(seen, Some(5));
+	assert!(!empty);
+
+	let none = None::<i32>
+		.tap_some(|v| seen = Some(*v * 2))
+		.tap_none(|| empty = true);
+	assert_eq!(none, None);
+	assert_eq!(seen, Some(5));
+	assert!(empty);
+
+	// Mutations made through `tap_some_mut` are written back into `Some`.
+	assert_eq!(Some(5).tap_some_mut(|v| *v += 1), Some(6));
+	assert_eq!(None::<i32>.tap_some_mut(|v| *v += 1), None);
+}
+
+#[test]
+fn tap_fallible() {
+	let mut calls = 0;
+
+	let ok: Result<i32, i32> =
+		Ok(5).tap_err(|_| calls += 1).tap_err_mut(|e| *e += 1);
+	assert_eq!(ok, Ok(5));
+	assert_eq!(calls, 0);
+
+	let ok: Result<i32, i32> = ok.tap_ok_mut(|v| *v *= 2).tap_ok(|_| calls += 1);
+	assert_eq!(ok, Ok(10));
+	assert_eq!(calls, 1);
+
+	let err: Result<i32, i32> = Err(5).tap_ok(|_| calls += 1);
+	assert_eq!(err.tap_err_mut(|e| *e = -*e), Err(-5));
+	assert_eq!(calls, 1);
+}
+
+#[test]
+fn conditional_taps() {
+	let never = |_: &i32| panic!("this effect must not run");
+	assert_eq!(5.tap_if(false, never), 5);
+	assert_eq!(5.tap_unless(true, never), 5);
+	assert_eq!(5.tap_mut_if(false, |_| panic!("must not run")), 5);
+	assert_eq!(5.tap_mut_unless(true, |_| panic!("must not run")), 5);
+
+	let mut seen = 0;
+	let _ = 5
+		.tap_if(true, |v| seen += *v)
+		.tap_unless(false, |v| seen += *v);
+	assert_eq!(seen, 10);
+	assert_eq!(5.tap_mut_if(true, |v| *v += 1), 6);
+	assert_eq!(5.tap_mut_unless(false, |v| *v += 1), 6);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn debug_taps_run_in_debug_builds() {
+	let mut seen = 0;
+	let val = 5.tap_dbg(|v| seen = *v).tap_mut_dbg(|v| *v += 1);
+	assert_eq!(seen, 5);
+	assert_eq!(val, 6);
+}
+
+#[test]
+fn tap_each_mut_is_lazy() {
+	let mut calls = 0;
+	let mut iter = vec![1, 2, 3].into_iter().tap_each_mut(|x| {
+		calls += 1;
+		*x *= 10;
+	});
+
+	assert_eq!(iter.next(), Some(10));
+	assert_eq!(iter.next_back(), Some(30));
+	drop(iter);
+	// The middle item was never yielded, so it was never tapped.
+	assert_eq!(calls, 2);
+}
+
+#[test]
+fn pipe_macro() {
+	fn add_one(x: i32) -> i32 {
+		x + 1
+	}
+
+	assert_eq!(tap::pipe!(5), 5);
+	assert_eq!(tap::pipe!(5 => add_one), 6);
+	assert_eq!(tap::pipe!(5 => |x| x * 2 => add_one), 11);
+	assert_eq!(tap::pipe!(-5i32 => .abs() => add_one => .pow(2)), 36);
+	assert_eq!(
+		tap::pipe!("Saluton" => .conv::<String>() => |s| s + ", mondo!" => .len()),
+		15,
+	);
+
+	// Stages move the value, like `.pipe()` does.
+	let words = vec!["a".to_string(), "b".to_string()];
+	assert_eq!(
+		tap::pipe!(words => |v| v.concat() => String::into_bytes),
+		b"ab"
+	);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn try_conv_array() {
+	let bytes = vec![0u8; 32];
+	let arr = bytes.try_conv_array::<32>().unwrap();
+	assert_eq!(arr, [0; 32]);
+
+	let err = vec![1, 2].try_conv_array::<3>().unwrap_err();
+	assert_eq!((err.expected(), err.got()), (3, 2));
+	assert_eq!(err.into_inner(), [1, 2]);
+}
+
+#[test]
+fn pipe_ref_on_unsized() {
+	let text: &str = "Saluton, mondo!";
+	// `Self` is `str` here, not `&str`.
+	let words = text.pipe_ref(|s: &str| s.split(' ').count());
+	assert_eq!(words, 2);
+
+	let mut bytes = *b"abc";
+	let slice: &mut [u8] = &mut bytes;
+	let first = slice.pipe_ref_mut(|s: &mut [u8]| {
+		s.make_ascii_uppercase();
+		s[0]
+	});
+	assert_eq!(first, b'A');
+	assert_eq!(&bytes, b"ABC");
+}
+
+#[test]
+fn view_pipes_on_unsized() {
+	let text: &str = "abc";
+	assert_eq!(text.pipe_borrow(|s: &str| s.len()), 3);
+	assert_eq!(text.pipe_as_ref(|b: &[u8]| b[0]), b'a');
+
+	let nums: &[i32] = &[1, 2, 3];
+	assert_eq!(nums.pipe_borrow(|s: &[i32]| s.iter().sum::<i32>()), 6);
+	assert_eq!(nums.pipe_as_ref(|s: &[i32]| s.len()), 3);
+
+	// `Tap` needs a `Sized` receiver, which the reference itself provides.
+	let mut seen = 0;
+	let same: &str = text.tap_ref(|s: &str| seen = s.len());
+	assert_eq!((same, seen), ("abc", 3));
+}
+
+#[test]
+fn tap_some_or_runs_one_branch() {
+	let (mut some, mut none) = (0, 0);
+	let val = Some(5).tap_some_or(|_| some += 1, || none += 1);
+	assert_eq!(val, Some(5));
+	assert_eq!((some, none), (1, 0));
+
+	let val = None::<i32>.tap_some_or(|_| some += 1, || none += 1);
+	assert_eq!(val, None);
+	assert_eq!((some, none), (1, 1));
+}
+
+#[test]
+fn tap_control_flow() {
+	use std::ops::ControlFlow;
+
+	let mut broke = false;
+	let flow: ControlFlow<&str, i32> = ControlFlow::Continue(5);
+	let flow = flow
+		.tap_break(|_| broke = true)
+		.tap_break_mut(|b| *b = "no");
+	assert_eq!(flow, ControlFlow::Continue(5));
+	assert!(!broke);
+	assert_eq!(flow.tap_continue_mut(|c| *c += 1), ControlFlow::Continue(6));
+
+	let flow: ControlFlow<&str, i32> = ControlFlow::Break("stop");
+	let flow = flow.tap_continue(|_| panic!("must not run"));
+	assert_eq!(flow.tap_break(|_| broke = true), ControlFlow::Break("stop"));
+	assert!(broke);
+}
+
+#[test]
+fn conv_each() {
+	let wide = [1u8, 2, 3].iter().copied().conv_each::<u32>();
+	let sum: u32 = wide.clone().sum();
+	assert_eq!(sum, 6);
+	assert_eq!(wide.rev().collect::<Vec<_>>(), [3, 2, 1]);
+
+	let mut narrow = [1i64, -1].iter().copied().try_conv_each::<u8>();
+	assert_eq!(narrow.len(), 2);
+	assert_eq!(narrow.next(), Some(Ok(1)));
+	assert!(narrow.next().unwrap().is_err());
+	assert_eq!(narrow.next(), None);
+}
+
+#[test]
+fn pipe_while_iteration_count() {
+	let mut steps = 0;
+	// 64 -> 32 -> 16 -> 8: three steps, and none after `pred` first holds.
+	let val = 64.pipe_while(
+		|n| *n <= 8,
+		|n| {
+			steps += 1;
+			n / 2
+		},
+	);
+	assert_eq!((val, steps), (8, 3));
+
+	let val = 4.pipe_while(|n| *n <= 8, |_| panic!("must not run"));
+	assert_eq!(val, 4);
+}
+
+#[test]
+fn taps_discard_return_values() {
+	let mut log = Vec::new();
+	let val = Some(5)
+		.tap_some(|v| log.push(*v))
+		.tap_some_mut(|v| std::mem::replace(v, 6));
+	assert_eq!(val, Some(6));
+	assert_eq!(log, [5]);
+
+	let res: Result<i32, &str> = Err("bad");
+	let res = res.tap_err(|e| e.len()).tap_ok(|v| v.to_string());
+	assert_eq!(res, Err("bad"));
+}
+
+#[test]
+fn pipe_some_short_circuits() {
+	let none: Option<i32> = None;
+	assert_eq!(none.pipe_some(|_| -> i32 { panic!("must not run") }), None);
+	assert_eq!(
+		none.try_pipe_some(|_| -> Option<i32> { panic!("must not run") }),
+		None,
+	);
+	assert_eq!(Some(2).pipe_some(|x| x * 2), Some(4));
+	assert_eq!(
+		Some(2).try_pipe_some(|x| if x > 5 { Some(x) } else { None }),
+		None
+	);
+}
+
+#[test]
+fn try_pipe_some_ok_keeps_errors() {
+	let none: Option<i32> = None;
+	let skipped: Result<Option<i32>, &str> =
+		none.try_pipe_some_ok(|_| panic!("must not run"));
+	assert_eq!(skipped, Ok(None));
+
+	let checked = |x: i32| if x > 5 { Ok(x) } else { Err("too small") };
+	assert_eq!(Some(8).try_pipe_some_ok(checked), Ok(Some(8)));
+	assert_eq!(Some(2).try_pipe_some_ok(checked), Err("too small"));
+}
+
+#[test]
+fn tap_err_with_skips_context_on_ok() {
+	let ok: Result<i32, &str> = Ok(1);
+	let ok = ok.tap_err_with(|| panic!("must not run"), |_, ()| ());
+	assert_eq!(ok, Ok(1));
+
+	let mut seen = None;
+	let err: Result<i32, &str> = Err("bad");
+	let err = err.tap_err_with(|| 42, |e, ctx| seen = Some((*e, ctx)));
+	assert_eq!(err, Err("bad"));
+	assert_eq!(seen, Some(("bad", 42)));
+}
+
+#[test]
+fn pipe_tap_sees_transformed_value() {
+	let mut seen = None;
+	let out = 5.pipe_tap(|x| x * 10, |y| seen = Some(*y));
+	assert_eq!(out, 50);
+	assert_eq!(seen, Some(50));
+}
+
+#[test]
+fn conv_default_picks_chosen_target() {
+	#[derive(Clone, Copy)]
+	struct Celsius(i16);
+
+	impl From<Celsius> for i16 {
+		fn from(c: Celsius) -> i16 {
+			c.0
+		}
+	}
+	impl From<Celsius> for f64 {
+		fn from(c: Celsius) -> f64 {
+			c.0 as f64 * 1.8 + 32.0
+		}
+	}
+
+	tap::conv_default!(Celsius => f64);
+
+	let c = Celsius(100);
+	assert_eq!(c.conv_default(), 212.0);
+	assert_eq!(c.conv::<i16>(), 100);
+}
+
+#[test]
+fn tap_filter_needs_no_clone() {
+	// Neither `Clone` nor `Copy`, so the kept value must be the original.
+	struct Token(u32);
+
+	let kept = Token(7).tap_filter(|t| t.0 > 5);
+	assert_eq!(kept.map(|t| t.0), Some(7));
+	assert!(Token(3).tap_filter(|t| t.0 > 5).is_none());
+}
+
+#[test]
+fn conv_pipe_names_the_target() {
+	// The closure calls methods on its argument, so its type cannot be inferred
+	// from the body; the turbofish supplies it.
+	let words = "a b c".conv_pipe::<String, _>(|s| s.split(' ').count());
+	assert_eq!(words, 3);
+
+	let bits = 7i64.try_conv_pipe::<u8, _>(|b| b.count_ones());
+	assert_eq!(bits, Ok(3));
+	assert!((-1i64).try_conv_pipe::<u8, _>(|b| b.count_ones()).is_err());
+}
+
+#[test]
+fn tap_and_flatten_runs_once_per_error() {
+	type Nested = Result<Result<u8, &'static str>, &'static str>;
+
+	let mut calls = 0;
+	let ok: Nested = Ok(Ok(1));
+	assert_eq!(ok.tap_and_flatten(|_| calls += 1), Ok(1));
+	assert_eq!(calls, 0);
+
+	let outer: Nested = Err("outer");
+	assert_eq!(outer.tap_and_flatten(|_| calls += 1), Err("outer"));
+	assert_eq!(calls, 1);
+
+	let inner: Nested = Ok(Err("inner"));
+	assert_eq!(inner.tap_and_flatten(|_| calls += 1), Err("inner"));
+	assert_eq!(calls, 2);
+}
+
+#[test]
+fn call_with_applies_function_pointers() {
+	fn double(x: u32) -> u32 {
+		x * 2
+	}
+
+	let ops: [fn(u32) -> u32; 2] = [double, u32::reverse_bits];
+	assert_eq!(ops[0].call_with(21), 42);
+	assert_eq!(ops[1].call_with(1), 1 << 31);
+	assert_eq!(double.call_with(4), 4.pipe(double));
+}
+
+#[test]
+fn tap_try_mut_preserves_value() {
+	let out: Result<Vec<i32>, ()> = vec![3, 1, 2].tap_try_mut(|v| {
+		v.sort();
+		Ok(())
+	});
+	assert_eq!(out, Ok(vec![1, 2, 3]));
+
+	let out = vec![1].tap_try_mut(|v| v.pop().map(drop).ok_or("empty"));
+	assert_eq!(out, Ok(vec![]));
+	let out =
+		Vec::<i32>::new().tap_try_mut(|v| v.pop().map(drop).ok_or("empty"));
+	assert_eq!(out, Err("empty"));
+}
+
+#[test]
+fn chain_matches_hand_written_code() {
+	use std::hint::black_box;
+
+	// Mirrors `benches/chain.rs`. `black_box` keeps the optimizer from folding
+	// the inputs away, so release-mode test runs exercise the inlined chain.
+	fn chain(x: u64) -> u64 {
+		x.pipe(|x| x.wrapping_mul(31))
+			.tap(|x| debug_assert!(*x >= 31 || *x == 0))
+			.tap_mut(|x| *x ^= 0x5555)
+			.pipe(|x| x.rotate_left(7))
+			.pipe_ref(|x| x.wrapping_add(1))
+	}
+
+	fn by_hand(x: u64) -> u64 {
+		let mut x = x.wrapping_mul(31);
+		debug_assert!(x >= 31 || x == 0);
+		x ^= 0x5555;
+		x.rotate_left(7).wrapping_add(1)
+	}
+
+	for x in [0, 1, 12345, u64::MAX / 31] {
+		assert_eq!(chain(black_box(x)), by_hand(black_box(x)));
+	}
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn tapped_runs_in_order() {
+	use std::cell::RefCell;
+	use tap::tap::Tapped;
+
+	let order = RefCell::new(Vec::new());
+	let pending = Tapped::new(1)
+		.tap(|v| order.borrow_mut().push(("first", *v)))
+		.tap_mut(|v| *v *= 10)
+		.tap(|v| order.borrow_mut().push(("second", *v)));
+
+	// nothing runs until `.run()`
+	assert!(order.borrow().is_empty());
+	assert_eq!(pending.run(), 10);
+	assert_eq!(*order.borrow(), [("first", 1), ("second", 10)]);
+}
@@ -0,0 +1,161 @@
+//! Calls every default-feature method through a single glob import, next to a
+//! type that derives the common standard traits, to catch any ambiguity between
+//! same-named methods on different traits.
+
+use core::ops::ControlFlow;
+use tap::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Level(u8);
+
+impl From<Level> for u8 {
+	fn from(level: Level) -> u8 {
+		level.0
+	}
+}
+
+impl AsRef<u8> for Level {
+	fn as_ref(&self) -> &u8 {
+		&self.0
+	}
+}
+
+impl AsMut<u8> for Level {
+	fn as_mut(&mut self) -> &mut u8 {
+		&mut self.0
+	}
+}
+
+tap::conv_default!(Level => u8);
+
+#[test]
+fn tap_methods() {
+	let mut vec = vec![Level(2), Level(1)];
+	vec = vec
+		.tap(|v| assert_eq!(v.len(), 2))
+		.tap_mut(|v| v.sort())
+		.tap_borrow(|s: &[Level]| assert_eq!(s[0], Level(1)))
+		.tap_borrow_mut(|s: &mut [Level]| s[0].0 += 1)
+		.tap_ref(|s: &[Level]| assert_eq!(s[0], Level(2)))
+		.tap_ref_mut(|s: &mut [Level]| s[0].0 -= 1)
+		.tap_deref(|s: &[Level]| assert_eq!(s[0], Level(1)))
+		.tap_deref_mut(|s: &mut [Level]| s.reverse())
+		.tap_if(true, |v| assert_eq!(v[0], Level(2)))
+		.tap_mut_if(false, Vec::clear)
+		.tap_unless(false, |v| assert_eq!(v.len(), 2))
+		.tap_mut_unless(true, Vec::clear)
+		.tap_assert(|v| !v.is_empty(), "has levels")
+		.tap_debug_assert(|v| !v.is_empty(), "has levels")
+		.tap_dbg(|_| ())
+		.tap_mut_dbg(|_| ())
+		.tap_borrow_dbg(|_: &[Level]| ())
+		.tap_borrow_mut_dbg(|_: &mut [Level]| ())
+		.tap_ref_dbg(|_: &[Level]| ())
+		.tap_ref_mut_dbg(|_: &mut [Level]| ())
+		.tap_deref_dbg(|_: &[Level]| ())
+		.tap_deref_mut_dbg(|_: &mut [Level]| ());
+	assert_eq!(vec, [Level(2), Level(1)]);
+	assert_eq!(Level(3).tap_filter(|l| l.0 > 1), Some(Level(3)));
+	assert_eq!(Level(3).tap_try_mut(|_| Ok::<_, ()>(())), Ok(Level(3)));
+}
+
+#[test]
+fn tap_container_methods() {
+	let opt = Some(Level(1))
+		.tap_some(|_| ())
+		.tap_some_mut(|l| l.0 += 1)
+		.tap_none(|| ())
+		.tap_some_or(|_| (), || ())
+		.tap_some_dbg(|_| ())
+		.tap_some_mut_dbg(|_| ())
+		.tap_none_dbg(|| ())
+		.tap_some_or_dbg(|_| (), || ());
+	assert_eq!(opt, Some(Level(2)));
+
+	let res: Result<Level, Level> = Ok(Level(1));
+	let res = res
+		.tap_ok(|_| ())
+		.tap_ok_mut(|l| l.0 += 1)
+		.tap_err(|_| ())
+		.tap_err_mut(|_| ())
+		.tap_err_with(|| (), |_, ()| ())
+		.tap_ok_dbg(|_| ())
+		.tap_ok_mut_dbg(|_| ())
+		.tap_err_dbg(|_| ())
+		.tap_err_mut_dbg(|_| ())
+		.tap_err_with_dbg(|| (), |_, ()| ());
+	assert_eq!(res, Ok(Level(2)));
+
+	let nested: Result<Result<Level, Level>, Level> = Ok(res);
+	let res = nested.tap_and_flatten(|_| ());
+	let res = Ok(res).tap_and_flatten_dbg(|_| ());
+	assert_eq!(res, Ok(Level(2)));
+
+	let flow: ControlFlow<Level, Level> = ControlFlow::Continue(Level(1));
+	let flow = flow
+		.tap_continue(|_| ())
+		.tap_continue_mut(|l| l.0 += 1)
+		.tap_break(|_| ())
+		.tap_break_mut(|_| ())
+		.tap_continue_dbg(|_| ())
+		.tap_continue_mut_dbg(|_| ())
+		.tap_break_dbg(|_| ())
+		.tap_break_mut_dbg(|_| ());
+	assert_eq!(flow, ControlFlow::Continue(Level(2)));
+
+	let levels: Vec<Level> = vec![Level(1)]
+		.into_iter()
+		.tap_each(|_| ())
+		.tap_each_mut(|l| l.0 += 1)
+		.collect();
+	assert_eq!(levels, [Level(2)]);
+}
+
+#[test]
+fn pipe_methods() {
+	let mut level = Level(1);
+	assert_eq!(level.pipe(|l| l.0), 1);
+	assert_eq!(level.pipe_into(|n: u8| n), 1);
+	assert_eq!(level.try_pipe_into(|n: u8| n), Ok(1));
+	assert_eq!(level.pipe_while(|l| l.0 > 4, |l| Level(l.0 * 2)), Level(8));
+	assert_eq!(level.pipe_tap(|l| l.0, |_| ()), 1);
+	assert_eq!(level.pipe_ref(|l| l.0), 1);
+	assert_eq!(level.pipe_ref_mut(|l| l.0), 1);
+	assert_eq!(level.pipe_as_ref(|n: &u8| *n), 1);
+	assert_eq!(level.pipe_as_mut(|n: &mut u8| *n), 1);
+
+	let mut vec = vec![level];
+	assert_eq!(vec.pipe_borrow(|s: &[Level]| s.len()), 1);
+	assert_eq!(vec.pipe_borrow_mut(|s: &mut [Level]| s.len()), 1);
+	assert_eq!(vec.pipe_deref(|s: &[Level]| s.len()), 1);
+	assert_eq!(vec.pipe_deref_mut(|s: &mut [Level]| s.len()), 1);
+
+	assert_eq!(Some(level).pipe_some(|l| l.0), Some(1));
+	assert_eq!(Some(level).try_pipe_some(|l| l.0.checked_sub(2)), None);
+	assert_eq!(
+		Some(level).try_pipe_some_ok(|l| l.0.try_conv::<i8>()),
+		Ok(Some(1))
+	);
+	assert_eq!((|l: Level| l.0).call_with(level), 1);
+}
+
+#[test]
+fn conv_methods() {
+	let level = Level(200);
+	assert_eq!(level.conv::<u8>(), 200);
+	assert_eq!(200i32.try_conv::<u8>(), Ok(200));
+	assert_eq!(200i32.conv_unwrap::<u8>(), 200);
+	assert_eq!(level.conv_default(), 200);
+	assert_eq!(level.conv_pipe::<u8, _>(|n| n.count_ones()), 3);
+	assert_eq!(200i32.try_conv_pipe::<u8, _>(|n| n.count_ones()), Ok(3));
+
+	let wide: Vec<u16> = vec![level]
+		.into_iter()
+		.conv_each::<u8>()
+		.conv_each()
+		.collect();
+	assert_eq!(wide, [200]);
+	let narrow: Vec<_> =
+		vec![300i32].into_iter().try_conv_each::<u8>().collect();
+	assert!(narrow[0].is_err());
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "tracing")]
+
+use std::{
+	fmt,
+	sync::{Arc, Mutex},
+};
+use tap::prelude::*;
+use tracing::{
+	field::{Field, Visit},
+	span::{Attributes, Id, Record},
+	Event, Level, Metadata, Subscriber,
+};
+
+/// A single captured event: its level and its fields, rendered with `Debug`.
+#[derive(Debug)]
+struct Captured {
+	level: Level,
+	target: String,
+	fields: Vec<(String, String)>,
+}
+
+impl Captured {
+	fn field(&self, name: &str) -> &str {
+		self.fields
+			.iter()
+			.find(|(key, _)| key == name)
+			.map(|(_, val)| val.as_str())
+			.unwrap_or_else(|| panic!("event has no `{}` field", name))
+	}
+}
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<Captured>>>);
+
+impl Visit for Captured {
+	fn record_str(&mut self, field: &Field, value: &str) {
+		self.fields
+			.push((field.name().to_string(), value.to_string()));
+	}
+
+	fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+		self.fields
+			.push((field.name().to_string(), format!("{:?}", value)));
+	}
+}
+
+impl Subscriber for Capture {
+	fn enabled(&self, _: &Metadata) -> bool {
+		true
+	}
+
+	fn new_span(&self, _: &Attributes) -> Id {
+		Id::from_u64(1)
+	}
+
+	fn record(&self, _: &Id, _: &Record) {}
+
+	fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+	fn event(&self, event: &Event) {
+		let mut captured = Captured {
+			level: *event.metadata().level(),
+			target: event.metadata().target().to_string(),
+			fields: vec![],
+		};
+		event.record(&mut captured);
+		self.0.lock().unwrap().push(captured);
+	}
+
+	fn enter(&self, _: &Id) {}
+
+	fn exit(&self, _: &Id) {}
+}
+
+fn capture(func: impl FnOnce()) -> Vec<Captured> {
+	let capture = Capture::default();
+	tracing::subscriber::with_default(capture.clone(), func);
+	let mut events = capture.0.lock().unwrap();
+	events.drain(..).collect()
+}
+
+#[test]
+fn tap_trace() {
+	let events = capture(|| {
+		let val = 5.tap_trace(Level::WARN, "five");
+		assert_eq!(val, 5);
+	});
+
+	assert_eq!(events.len(), 1);
+	let event = &events[0];
+	assert_eq!(event.level, Level::WARN);
+	assert_eq!(event.field("message"), "five");
+	assert_eq!(event.field("value"), "5");
+	assert_eq!(event.field("caller.file"), file!());
+	// the event is emitted inside the crate, not at the call site
+	assert_eq!(event.target, "tap::tap");
+}
+
+#[test]
+fn tap_err_trace() {
+	let events = capture(|| {
+		let ok: Result<i32, &str> = Ok(1);
+		assert_eq!(ok.tap_err_trace("unused"), Ok(1));
+		assert_eq!(Some(1).tap_err_trace("unused"), Some(1));
+
+		let err: Result<i32, &str> = Err("bad");
+		assert_eq!(err.tap_err_trace("failed"), Err("bad"));
+		assert_eq!(None::<i32>.tap_err_trace("missing"), None);
+	});
+
+	assert_eq!(events.len(), 2);
+	assert!(events.iter().all(|event| event.level == Level::ERROR));
+	assert!(events.iter().all(|event| event.target == "tap::tap"));
+	assert_eq!(events[0].field("message"), "failed");
+	assert_eq!(events[0].field("value"), "\"bad\"");
+	assert_eq!(events[1].field("message"), "missing");
+	assert_eq!(events[1].field("value"), "None");
+}
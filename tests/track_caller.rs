@@ -0,0 +1,67 @@
+//! Checks that panicking helpers report the caller's location.
+//!
+//! This lives in its own test binary because it replaces the process-wide
+//! panic hook, and the tests that do so take turns through `HOOK`.
+
+use std::{
+	panic::{self, UnwindSafe},
+	sync::{Arc, Mutex},
+};
+use tap::prelude::*;
+
+static HOOK: Mutex<()> = Mutex::new(());
+
+/// Runs `func`, which must panic, and returns the panic's message and the file
+/// it was reported in.
+fn panic_site(func: impl FnOnce() + UnwindSafe) -> (String, String) {
+	let _guard = HOOK.lock().unwrap_or_else(|err| err.into_inner());
+	let site = Arc::new(Mutex::new(None));
+	let hook_site = site.clone();
+	panic::set_hook(Box::new(move |info| {
+		let file = info.location().map(|loc| loc.file().to_string());
+		let msg =
+			info.payload()
+				.downcast_ref::<String>()
+				.cloned()
+				.or_else(|| {
+					info.payload().downcast_ref::<&str>().map(|s| s.to_string())
+				});
+		*hook_site.lock().unwrap() = Some((msg.unwrap(), file.unwrap()));
+	}));
+	let result = panic::catch_unwind(func);
+	let _ = panic::take_hook();
+
+	assert!(result.is_err(), "the function did not panic");
+	let site = site.lock().unwrap().take();
+	site.unwrap()
+}
+
+#[test]
+fn conv_unwrap() {
+	let (msg, file) = panic_site(|| {
+		300i32.conv_unwrap::<u8>();
+	});
+	assert!(msg.starts_with("conversion failed"), "{}", msg);
+	assert_eq!(file, file!());
+}
+
+#[test]
+fn tap_assert() {
+	assert_eq!(5.tap_assert(|v| *v == 5, "unused"), 5);
+
+	let (msg, file) = panic_site(|| {
+		5.tap_assert(|v| *v > 10, "value is too small");
+	});
+	assert_eq!(msg, "value is too small");
+	assert_eq!(file, file!());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn tap_debug_assert() {
+	let (msg, file) = panic_site(|| {
+		5.tap_debug_assert(|v| *v > 10, "value is too small");
+	});
+	assert_eq!(msg, "value is too small");
+	assert_eq!(file, file!());
+}
@@ -0,0 +1,29 @@
+use std::{env, process::Command};
+
+/// The compiler release that the `.stderr` snapshots were recorded with.
+///
+/// The snapshots pin rustc's own diagnostic wording, which changes between
+/// releases, so the cases only run on this release. Re-record them with
+/// `TRYBUILD=overwrite cargo +<version> test --test ui` when bumping it.
+const UI_RUSTC: &str = "rustc 1.95.0 ";
+
+#[test]
+fn ui() {
+	let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+	let version = Command::new(rustc)
+		.arg("--version")
+		.output()
+		.map(|out| String::from_utf8_lossy(&out.stdout).into_owned())
+		.unwrap_or_default();
+	if !version.starts_with(UI_RUSTC) {
+		eprintln!(
+			"skipping UI tests: snapshots are for {}, found {}",
+			UI_RUSTC.trim(),
+			version.trim(),
+		);
+		return;
+	}
+
+	let cases = trybuild::TestCases::new();
+	cases.compile_fail("tests/ui/*.rs");
+}
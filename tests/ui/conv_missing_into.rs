@@ -0,0 +1,10 @@
+use tap::conv::{Conv, TryConv};
+
+struct Celsius(f64);
+struct Kelvin(f64);
+
+fn main() {
+	// the error names the missing `From` impl at the call site
+	let _ = Celsius(0.0).conv::<Kelvin>();
+	let _ = Celsius(0.0).try_conv::<Kelvin>();
+}
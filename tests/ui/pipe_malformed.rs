@@ -0,0 +1,4 @@
+fn main() {
+	// a stage is missing between the arrows
+	let _ = tap::pipe!(5 => => i32::abs);
+}